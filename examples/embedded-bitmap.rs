@@ -0,0 +1,22 @@
+//! Embeds the backgrounds at compile time, no image decoding at runtime.
+
+#[macro_use]
+extern crate logitech_lcd_macros;
+extern crate logitech_lcd;
+
+use logitech_lcd::Driver;
+
+static MONO_LOGO: &[u8] = include_mono_bitmap!("examples/rust-logo-32x32-blk.png",
+    filter = "nearest", invert = true);
+
+static COLOR_LOGO: &[u8] = include_color_bitmap!("examples/rust-logo-128x128.png",
+    filter = "lanczos3", fit = "letterbox");
+
+fn main() {
+    let mut driver = Driver::init_either("Embedded bitmap app").unwrap();
+    driver.set_mono_background(MONO_LOGO).unwrap();
+    driver.set_color_background(COLOR_LOGO).unwrap();
+    driver.update();
+
+    std::thread::sleep(std::time::Duration::from_millis(10000));
+}
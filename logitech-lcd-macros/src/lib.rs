@@ -0,0 +1,246 @@
+//! Compile-time bitmap embedding for [logitech-lcd](https://docs.rs/logitech-lcd/).
+//!
+//! The macros in this crate decode an image file while your crate is being compiled,
+//! resize it to the LCD resolution and convert it into the exact byte layout expected by
+//! `Driver::set_mono_background` or `Driver::set_color_background`. The result is a
+//! `&'static [u8; N]` where `N` is computed from the constants in `logitech_lcd`, so a size
+//! mismatch is a compile error rather than a runtime panic.
+//!
+//! PNG, JPEG and GIF files are decoded with the `image` crate. Netpbm, BMP and XBM
+//! files are decoded with `logitech_lcd::asset`. Paths are relative to the directory
+//! containing the `Cargo.toml` of the crate invoking the macro. The file is tracked by
+//! cargo, so the bitmap is regenerated when it changes.
+//!
+//! ## Options
+//! Options follow the path as `name = value` pairs.
+//!
+//! - `filter`: `"nearest"`, `"bilinear"` (default) or `"lanczos3"`.
+//! - `fit`: `"stretch"`, `"letterbox"` (default) or `"crop"`.
+//! - `dither`: Monochrome only. `"threshold"` (default), `"bayer"`, `"floyd-steinberg"`
+//!   or `"atkinson"`.
+//! - `invert`: Monochrome only. When `true`, dark opaque pixels turn on instead of light
+//!   pixels. Use this for black artwork on a white or transparent background.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! #[macro_use]
+//! extern crate logitech_lcd_macros;
+//! extern crate logitech_lcd;
+//!
+//! static LOGO: &[u8] = include_mono_bitmap!("assets/logo.png", dither = "bayer", invert = true);
+//! static SPLASH: &[u8] = include_color_bitmap!("assets/splash.png", fit = "crop");
+//!
+//! fn main() {
+//!     let mut driver = logitech_lcd::Driver::init_either("Embedded").unwrap();
+//!     driver.set_mono_background(LOGO).unwrap();
+//!     driver.set_color_background(SPLASH).unwrap();
+//!     driver.update();
+//! }
+//! ```
+//!
+
+extern crate image;
+extern crate logitech_lcd;
+extern crate proc_macro;
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+use logitech_lcd::asset::Image;
+use logitech_lcd::dither::{self, Dither};
+use logitech_lcd::resample::{self, luma, Filter, Fit, Format};
+use logitech_lcd::{MONO_WIDTH, MONO_HEIGHT};
+
+use proc_macro::TokenStream;
+use proc_macro2::Literal;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, ExprLit, Lit, LitStr, MetaNameValue, Token};
+
+use std::path::PathBuf;
+
+/// Macro input, a path literal followed by optional `name = value` pairs.
+struct Input {
+    path: LitStr,
+    options: Vec<MetaNameValue>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Input> {
+        let path = input.parse::<LitStr>()?;
+        let mut options = Vec::new();
+        if input.parse::<Option<Token![,]>>()?.is_some() {
+            options.extend(Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?);
+        }
+        Ok(Input { path, options })
+    }
+}
+
+/// Parsed options shared by both macros.
+struct Options {
+    filter: Filter,
+    fit: Fit,
+    dither: Dither,
+    invert: bool,
+}
+
+fn lit_str(option: &MetaNameValue) -> syn::Result<String> {
+    match option.value {
+        Expr::Lit(ExprLit { lit: Lit::Str(ref s), .. }) => Ok(s.value()),
+        _ => Err(syn::Error::new_spanned(&option.value, "expected a string literal")),
+    }
+}
+
+fn lit_bool(option: &MetaNameValue) -> syn::Result<bool> {
+    match option.value {
+        Expr::Lit(ExprLit { lit: Lit::Bool(ref b), .. }) => Ok(b.value),
+        _ => Err(syn::Error::new_spanned(&option.value, "expected `true` or `false`")),
+    }
+}
+
+fn parse_options(options: &[MetaNameValue], mono: bool) -> syn::Result<Options> {
+    let mut parsed = Options {
+        filter: Filter::Bilinear,
+        fit: Fit::Letterbox,
+        dither: Dither::Threshold,
+        invert: false,
+    };
+
+    for option in options {
+        let name = option.path.get_ident().map(|i| i.to_string()).unwrap_or_default();
+        match &name[..] {
+            "filter" => parsed.filter = match &lit_str(option)?[..] {
+                "nearest" => Filter::Nearest,
+                "bilinear" => Filter::Bilinear,
+                "lanczos3" => Filter::Lanczos3,
+                _ => return Err(syn::Error::new_spanned(&option.value,
+                    "expected \"nearest\", \"bilinear\" or \"lanczos3\"")),
+            },
+            "fit" => parsed.fit = match &lit_str(option)?[..] {
+                "stretch" => Fit::Stretch,
+                "letterbox" => Fit::Letterbox,
+                "crop" => Fit::Crop,
+                _ => return Err(syn::Error::new_spanned(&option.value,
+                    "expected \"stretch\", \"letterbox\" or \"crop\"")),
+            },
+            "dither" if mono => parsed.dither = match &lit_str(option)?[..] {
+                "threshold" => Dither::Threshold,
+                "bayer" => Dither::Bayer,
+                "floyd-steinberg" => Dither::FloydSteinberg,
+                "atkinson" => Dither::Atkinson,
+                _ => return Err(syn::Error::new_spanned(&option.value,
+                    "expected \"threshold\", \"bayer\", \"floyd-steinberg\" or \"atkinson\"")),
+            },
+            "invert" if mono => parsed.invert = lit_bool(option)?,
+            _ => return Err(syn::Error::new_spanned(&option.path, "unknown option")),
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// Reads and decodes the image at `path`, relative to the invoking crate's manifest.
+fn load(path: &LitStr) -> syn::Result<(PathBuf, Image)> {
+    let root = std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default();
+    let full = root.join(path.value());
+
+    let data = std::fs::read(&full).map_err(|e| syn::Error::new_spanned(path,
+        format!("couldn't read {}: {}", full.display(), e)))?;
+
+    let decoded = match image::load_from_memory(&data) {
+        Ok(img) => {
+            let rgba = img.to_rgba8();
+            let (width, height) = (rgba.width() as usize, rgba.height() as usize);
+            Image::new(width, height, Format::Rgba, rgba.into_raw())
+        },
+        Err(_) => Image::decode(&data).map_err(|e| syn::Error::new_spanned(path,
+            format!("couldn't decode {}: {}", full.display(), e)))?,
+    };
+
+    if decoded.width() == 0 || decoded.height() == 0 {
+        return Err(syn::Error::new_spanned(path, "image has no pixels"));
+    }
+
+    Ok((full, decoded))
+}
+
+/// Converts an image into luma where dark opaque pixels are bright, by compositing
+/// onto white and inverting.
+fn inverted_luma(img: &Image) -> Vec<u8> {
+    img.data().chunks(img.format().channels()).map(|p| {
+        let (l, a) = match img.format() {
+            Format::Luma => (p[0], 255),
+            Format::Rgb => (luma(p[0], p[1], p[2]), 255),
+            Format::Rgba => (luma(p[0], p[1], p[2]), p[3]),
+            Format::Bgra => (luma(p[2], p[1], p[0]), p[3]),
+        };
+        let (l, a) = (l as u32, a as u32);
+        let over_white = (l * a + 255 * (255 - a) + 127) / 255;
+        255 - over_white as u8
+    }).collect()
+}
+
+fn expand(path: PathBuf, len: proc_macro2::TokenStream, bytes: &[u8]) -> TokenStream {
+    let path = path.to_string_lossy().into_owned();
+    let bytes = Literal::byte_string(bytes);
+
+    let expanded = quote! {
+        {
+            // Makes cargo rebuild when the asset changes.
+            const _: &[u8] = include_bytes!(#path);
+            static BITMAP: [u8; #len] = *#bytes;
+            &BITMAP
+        }
+    };
+    expanded.into()
+}
+
+/// Embeds an image as a monochrome background.
+///
+/// Expands to a `&'static [u8; MONO_WIDTH * MONO_HEIGHT]`, see the
+/// [crate documentation](index.html) for the available options.
+#[proc_macro]
+pub fn include_mono_bitmap(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as Input);
+
+    let result = parse_options(&input.options, true).and_then(|options| {
+        let (path, img) = load(&input.path)?;
+
+        let mut bitmap = if options.invert {
+            let luma = inverted_luma(&img);
+            resample::mono_background(&luma, img.width(), img.height(), Format::Luma,
+                options.filter, options.fit)
+        } else {
+            img.mono_background(options.filter, options.fit)
+        };
+        dither::dither(&mut bitmap, MONO_WIDTH, MONO_HEIGHT, options.dither);
+
+        let len = quote!(::logitech_lcd::MONO_WIDTH * ::logitech_lcd::MONO_HEIGHT);
+        Ok(expand(path, len, &bitmap))
+    });
+
+    result.unwrap_or_else(|e| e.to_compile_error().into())
+}
+
+/// Embeds an image as a color background.
+///
+/// Expands to a `&'static [u8; COLOR_WIDTH * COLOR_HEIGHT * 4]` in BGRA order, see the
+/// [crate documentation](index.html) for the available options.
+#[proc_macro]
+pub fn include_color_bitmap(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as Input);
+
+    let result = parse_options(&input.options, false).and_then(|options| {
+        let (path, img) = load(&input.path)?;
+        let bitmap = img.color_background(options.filter, options.fit);
+
+        let len = quote!(::logitech_lcd::COLOR_WIDTH * ::logitech_lcd::COLOR_HEIGHT * 4);
+        Ok(expand(path, len, &bitmap))
+    });
+
+    result.unwrap_or_else(|e| e.to_compile_error().into())
+}
@@ -0,0 +1,759 @@
+//! Dependency-free loading and saving of bitmap assets.
+//!
+//! Supports Netpbm (PBM, PGM and PPM, both plain `P1`–`P3` and raw `P4`–`P6`),
+//! uncompressed Windows BMP (1, 4, 8, 16, 24 and 32 bits per pixel) and X BitMap (XBM).
+//! Decoded files are held in an [Image](struct.Image.html) which converts into buffers for
+//! [set_mono_background](../struct.Driver.html#method.set_mono_background) and
+//! [set_color_background](../struct.Driver.html#method.set_color_background). Screen
+//! buffers can be wrapped in an [Image](struct.Image.html) and written back out.
+//!
+//! In the 1-bit formats, PBM and XBM, a set bit is an on pixel. It decodes to luma 255
+//! and pixels with a luma >= 128 are written as set bits, matching how the monochrome
+//! LCD thresholds its background.
+//!
+//! ```
+//! use logitech_lcd::asset::Image;
+//! use logitech_lcd::resample::{Filter, Fit};
+//!
+//! let xbm = b"#define dot_width 2
+//! #define dot_height 2
+//! static unsigned char dot_bits[] = { 0x01, 0x02 };";
+//!
+//! let icon = Image::decode(xbm).unwrap();
+//! assert_eq!((icon.width(), icon.height()), (2, 2));
+//!
+//! let background = icon.mono_background(Filter::Nearest, Fit::Letterbox);
+//! assert_eq!(background.len(), logitech_lcd::MONO_WIDTH * logitech_lcd::MONO_HEIGHT);
+//!
+//! let mut pbm = Vec::new();
+//! Image::from_mono_background(&background).write_pbm(&mut pbm, true).unwrap();
+//! ```
+
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use resample::{self, Filter, Fit, Format};
+use {Error, COLOR_WIDTH, COLOR_HEIGHT, MONO_WIDTH, MONO_HEIGHT};
+
+/// A decoded bitmap.
+///
+/// Pixels are stored row-major and tightly packed in the layout given by
+/// [format](#method.format).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    format: Format,
+    data: Vec<u8>,
+}
+
+impl Image {
+    /// Creates an image from raw pixel data.
+    ///
+    /// Panics:
+    /// - If data's length is not `width * height * format.channels()` bytes.
+    ///
+    pub fn new(width: usize, height: usize, format: Format, data: Vec<u8>) -> Image {
+        assert_eq!(data.len(), width * height * format.channels());
+        Image { width, height, format, data }
+    }
+
+    /// Wraps a copy of a monochrome background, 160x43 bytes.
+    ///
+    /// Panics:
+    /// - If mono_bitmap's length is not 160x43 bytes.
+    ///
+    pub fn from_mono_background(mono_bitmap: &[u8]) -> Image {
+        Image::new(MONO_WIDTH, MONO_HEIGHT, Format::Luma, mono_bitmap.to_vec())
+    }
+
+    /// Wraps a copy of a color background, 320x240 BGRA pixels.
+    ///
+    /// Panics:
+    /// - If color_bitmap's length is not 320x240x4 bytes.
+    ///
+    pub fn from_color_background(color_bitmap: &[u8]) -> Image {
+        Image::new(COLOR_WIDTH, COLOR_HEIGHT, Format::Bgra, color_bitmap.to_vec())
+    }
+
+    /// Reads and decodes a file, the format is detected from its contents.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Image, Error> {
+        let mut data = Vec::new();
+        File::open(path)
+            .and_then(|mut f| f.read_to_end(&mut data))
+            .map_err(Error::Io)?;
+        Image::decode(&data)
+    }
+
+    /// Decodes an in-memory file, the format is detected from its contents.
+    pub fn decode(data: &[u8]) -> Result<Image, Error> {
+        if data.len() >= 2 && data[0] == b'P' && data[1] >= b'1' && data[1] <= b'6' {
+            decode_netpbm(data)
+        } else if data.starts_with(b"BM") {
+            decode_bmp(data)
+        } else if looks_like_xbm(data) {
+            decode_xbm(data)
+        } else {
+            Err(Error::Decode("Unrecognized image format."))
+        }
+    }
+
+    /// Width in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Pixel layout of [data](#method.data).
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Raw pixel data.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Consumes the image, returning the raw pixel data.
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Converts the image into a monochrome background, 160x43 bytes.
+    ///
+    /// See [resample::mono_background](../resample/fn.mono_background.html).
+    pub fn mono_background(&self, filter: Filter, fit: Fit) -> Vec<u8> {
+        resample::mono_background(&self.data, self.width, self.height, self.format, filter, fit)
+    }
+
+    /// Converts the image into a color background, 320x240 BGRA pixels.
+    ///
+    /// See [resample::color_background](../resample/fn.color_background.html).
+    pub fn color_background(&self, filter: Filter, fit: Fit) -> Vec<u8> {
+        resample::color_background(&self.data, self.width, self.height, self.format, filter, fit)
+    }
+
+    /// Writes the image as a PBM, thresholding at 128.
+    ///
+    /// Parameters:
+    /// - raw: Write the binary `P4` variant instead of the plain text `P1`.
+    ///
+    pub fn write_pbm<W: Write>(&self, w: &mut W, raw: bool) -> Result<(), Error> {
+        let luma = resample::to_luma(&self.data, self.format);
+        let mut out = Vec::new();
+
+        if raw {
+            write!(out, "P4\n{} {}\n", self.width, self.height).unwrap();
+            for row in luma.chunks(self.width) {
+                out.extend(pack_bits(row, false));
+            }
+        } else {
+            write!(out, "P1\n{} {}\n", self.width, self.height).unwrap();
+            for row in luma.chunks(self.width) {
+                for (i, &l) in row.iter().enumerate() {
+                    // Plain PBM lines should not exceed 70 characters.
+                    if i > 0 && i % 70 == 0 {
+                        out.push(b'\n');
+                    }
+                    out.push(if l >= 128 { b'1' } else { b'0' });
+                }
+                out.push(b'\n');
+            }
+        }
+
+        w.write_all(&out).map_err(Error::Io)
+    }
+
+    /// Writes the image as a PGM with a maxval of 255.
+    ///
+    /// Parameters:
+    /// - raw: Write the binary `P5` variant instead of the plain text `P2`.
+    ///
+    pub fn write_pgm<W: Write>(&self, w: &mut W, raw: bool) -> Result<(), Error> {
+        let luma = resample::to_luma(&self.data, self.format);
+        let out = if raw {
+            let mut out = format!("P5\n{} {}\n255\n", self.width, self.height).into_bytes();
+            out.extend_from_slice(&luma);
+            out
+        } else {
+            let mut out = format!("P2\n{} {}\n255\n", self.width, self.height).into_bytes();
+            write_plain_samples(&mut out, &luma, self.width);
+            out
+        };
+
+        w.write_all(&out).map_err(Error::Io)
+    }
+
+    /// Writes the image as a PPM with a maxval of 255, alpha is discarded.
+    ///
+    /// Parameters:
+    /// - raw: Write the binary `P6` variant instead of the plain text `P3`.
+    ///
+    pub fn write_ppm<W: Write>(&self, w: &mut W, raw: bool) -> Result<(), Error> {
+        let rgb = self.to_rgb();
+        let out = if raw {
+            let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+            out.extend_from_slice(&rgb);
+            out
+        } else {
+            let mut out = format!("P3\n{} {}\n255\n", self.width, self.height).into_bytes();
+            write_plain_samples(&mut out, &rgb, self.width * 3);
+            out
+        };
+
+        w.write_all(&out).map_err(Error::Io)
+    }
+
+    /// Writes the image as an uncompressed BMP.
+    ///
+    /// Grayscale images are written with 8 bits per pixel and a gray palette, RGB images
+    /// with 24 bits per pixel and images with alpha with 32 bits per pixel.
+    pub fn write_bmp<W: Write>(&self, w: &mut W) -> Result<(), Error> {
+        let (bpp, palette) = match self.format {
+            Format::Luma => (8, 256),
+            Format::Rgb => (24, 0),
+            Format::Rgba | Format::Bgra => (32, 0),
+        };
+
+        let stride = (self.width * bpp / 8 + 3) & !3;
+        let offset = 14 + 40 + palette * 4;
+        let size = offset + stride * self.height;
+
+        let mut out = Vec::with_capacity(size);
+        out.extend_from_slice(b"BM");
+        push_u32(&mut out, size as u32);
+        push_u32(&mut out, 0);
+        push_u32(&mut out, offset as u32);
+
+        push_u32(&mut out, 40);
+        push_u32(&mut out, self.width as u32);
+        push_u32(&mut out, self.height as u32);
+        push_u16(&mut out, 1);
+        push_u16(&mut out, bpp as u16);
+        push_u32(&mut out, 0); // BI_RGB
+        push_u32(&mut out, (stride * self.height) as u32);
+        push_u32(&mut out, 2835); // 72 DPI
+        push_u32(&mut out, 2835);
+        push_u32(&mut out, palette as u32);
+        push_u32(&mut out, 0);
+
+        for i in 0..palette {
+            out.extend_from_slice(&[i as u8, i as u8, i as u8, 0]);
+        }
+
+        let bgra = resample::to_bgra(&self.data, self.format);
+        // Rows are stored bottom-up, padded to 4 bytes.
+        for y in (0..self.height).rev() {
+            let start = out.len();
+            for px in bgra[y * self.width * 4..(y + 1) * self.width * 4].chunks(4) {
+                match bpp {
+                    8 => out.push(px[0]),
+                    24 => out.extend_from_slice(&px[..3]),
+                    _ => out.extend_from_slice(px),
+                }
+            }
+            while out.len() - start < stride {
+                out.push(0);
+            }
+        }
+
+        w.write_all(&out).map_err(Error::Io)
+    }
+
+    /// Writes the image as an X11 XBM, thresholding at 128.
+    ///
+    /// Parameters:
+    /// - name: The C identifier prefix for the `_width`, `_height` and `_bits` symbols.
+    ///
+    pub fn write_xbm<W: Write>(&self, w: &mut W, name: &str) -> Result<(), Error> {
+        let luma = resample::to_luma(&self.data, self.format);
+        let bits = luma.chunks(self.width)
+            .flat_map(|row| pack_bits(row, true))
+            .collect::<Vec<u8>>();
+
+        let mut out = String::new();
+        out.push_str(&format!("#define {}_width {}\n", name, self.width));
+        out.push_str(&format!("#define {}_height {}\n", name, self.height));
+        out.push_str(&format!("static unsigned char {}_bits[] = {{", name));
+        for (i, b) in bits.iter().enumerate() {
+            if i % 12 == 0 {
+                out.push_str("\n  ");
+            } else {
+                out.push(' ');
+            }
+            out.push_str(&format!("0x{:02x}", b));
+            if i + 1 < bits.len() {
+                out.push(',');
+            }
+        }
+        out.push_str(" };\n");
+
+        w.write_all(out.as_bytes()).map_err(Error::Io)
+    }
+
+    fn to_rgb(&self) -> Vec<u8> {
+        match self.format {
+            Format::Rgb => self.data.clone(),
+            _ => {
+                let bgra = resample::to_bgra(&self.data, self.format);
+                let mut rgb = Vec::with_capacity(self.width * self.height * 3);
+                for p in bgra.chunks(4) {
+                    rgb.extend_from_slice(&[p[2], p[1], p[0]]);
+                }
+                rgb
+            },
+        }
+    }
+}
+
+/// Packs a row of luma samples into bits, a sample >= 128 is a set bit.
+fn pack_bits(row: &[u8], lsb_first: bool) -> Vec<u8> {
+    row.chunks(8).map(|chunk| {
+        chunk.iter().enumerate().fold(0u8, |byte, (i, &l)| {
+            if l < 128 {
+                byte
+            } else if lsb_first {
+                byte | (1 << i)
+            } else {
+                byte | (0x80 >> i)
+            }
+        })
+    }).collect()
+}
+
+fn write_plain_samples(out: &mut Vec<u8>, samples: &[u8], per_row: usize) {
+    for row in samples.chunks(per_row) {
+        let line = row.iter().map(|s| s.to_string()).collect::<Vec<String>>().join(" ");
+        out.extend_from_slice(line.as_bytes());
+        out.push(b'\n');
+    }
+}
+
+fn push_u16(out: &mut Vec<u8>, v: u16) {
+    out.extend_from_slice(&[v as u8, (v >> 8) as u8]);
+}
+
+fn push_u32(out: &mut Vec<u8>, v: u32) {
+    out.extend_from_slice(&[v as u8, (v >> 8) as u8, (v >> 16) as u8, (v >> 24) as u8]);
+}
+
+/// Tokenizer for Netpbm headers and plain rasters.
+struct Tokens<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn skip_whitespace(&mut self) {
+        while self.pos < self.data.len() {
+            match self.data[self.pos] {
+                b'#' => {
+                    while self.pos < self.data.len() && self.data[self.pos] != b'\n' {
+                        self.pos += 1;
+                    }
+                },
+                b' ' | b'\t' | b'\r' | b'\n' | 0x0b | 0x0c => self.pos += 1,
+                _ => return,
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<u32, Error> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self.pos < self.data.len() && self.data[self.pos].is_ascii_digit() {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err(Error::Decode("Expected a number in Netpbm data."));
+        }
+        ::std::str::from_utf8(&self.data[start..self.pos]).unwrap()
+            .parse::<u32>()
+            .map_err(|_| Error::Decode("Number out of range in Netpbm data."))
+    }
+
+    fn bit(&mut self) -> Result<bool, Error> {
+        self.skip_whitespace();
+        match self.data.get(self.pos) {
+            Some(&b'0') => { self.pos += 1; Ok(false) },
+            Some(&b'1') => { self.pos += 1; Ok(true) },
+            _ => Err(Error::Decode("Expected a bit in PBM data.")),
+        }
+    }
+}
+
+fn decode_netpbm(data: &[u8]) -> Result<Image, Error> {
+    let kind = data[1];
+    let mut t = Tokens { data, pos: 2 };
+
+    let width = t.number()? as usize;
+    let height = t.number()? as usize;
+    let maxval = match kind {
+        b'1' | b'4' => 1,
+        _ => t.number()?,
+    };
+
+    if width == 0 || height == 0 {
+        return Err(Error::Decode("Netpbm image has no pixels."));
+    }
+    if maxval == 0 || maxval > 65535 {
+        return Err(Error::Decode("Invalid Netpbm maxval."));
+    }
+
+    let format = match kind {
+        b'1' | b'2' | b'4' | b'5' => Format::Luma,
+        _ => Format::Rgb,
+    };
+    let samples = width.checked_mul(height)
+        .and_then(|pixels| pixels.checked_mul(format.channels()))
+        .ok_or(Error::Decode("Netpbm image is too large."))?;
+    let scale = |v: u32| ((v.min(maxval) * 255 + maxval / 2) / maxval) as u8;
+
+    // Every sample takes at least one byte, so the rest of the input bounds the size of
+    // the image before anything is allocated.
+    let needed = match kind {
+        b'4' => width.div_ceil(8).checked_mul(height),
+        b'5' | b'6' if maxval > 255 => samples.checked_mul(2),
+        _ => Some(samples),
+    };
+    if needed.is_none_or(|n| n > data.len().saturating_sub(t.pos)) {
+        return Err(Error::Decode("Truncated Netpbm raster."));
+    }
+
+    let mut pixels = Vec::with_capacity(samples);
+    match kind {
+        b'1' => for _ in 0..samples {
+            pixels.push(if t.bit()? { 255 } else { 0 });
+        },
+        b'2' | b'3' => for _ in 0..samples {
+            let v = t.number()?;
+            pixels.push(scale(v));
+        },
+        _ => {
+            // A single whitespace character separates the header from the raster.
+            let raster = &data[(t.pos + 1).min(data.len())..];
+            if kind == b'4' {
+                let stride = width.div_ceil(8);
+                if raster.len() < stride * height {
+                    return Err(Error::Decode("Truncated PBM raster."));
+                }
+                for row in raster.chunks(stride).take(height) {
+                    for x in 0..width {
+                        let set = row[x / 8] & (0x80 >> (x % 8)) != 0;
+                        pixels.push(if set { 255 } else { 0 });
+                    }
+                }
+            } else {
+                let wide = maxval > 255;
+                let needed = if wide { samples * 2 } else { samples };
+                if raster.len() < needed {
+                    return Err(Error::Decode("Truncated Netpbm raster."));
+                }
+                if wide {
+                    for s in raster[..needed].chunks(2) {
+                        pixels.push(scale((s[0] as u32) << 8 | s[1] as u32));
+                    }
+                } else {
+                    pixels.extend(raster[..needed].iter().map(|&s| scale(s as u32)));
+                }
+            }
+        },
+    }
+
+    Ok(Image::new(width, height, format, pixels))
+}
+
+fn read_u16(data: &[u8], at: usize) -> Result<u16, Error> {
+    data.get(at..at + 2)
+        .map(|b| b[0] as u16 | (b[1] as u16) << 8)
+        .ok_or(Error::Decode("Truncated BMP header."))
+}
+
+fn read_u32(data: &[u8], at: usize) -> Result<u32, Error> {
+    data.get(at..at + 4)
+        .map(|b| b[0] as u32 | (b[1] as u32) << 8 | (b[2] as u32) << 16 | (b[3] as u32) << 24)
+        .ok_or(Error::Decode("Truncated BMP header."))
+}
+
+/// Extracts the channel selected by mask from a pixel, scaled to 8 bits.
+fn channel(px: u32, mask: u32) -> u8 {
+    if mask == 0 {
+        return 0;
+    }
+    let shift = mask.trailing_zeros();
+    let max = mask >> shift;
+    let v = (px & mask) >> shift;
+    ((v as u64 * 255 + max as u64 / 2) / max as u64) as u8
+}
+
+fn decode_bmp(data: &[u8]) -> Result<Image, Error> {
+    let offset = read_u32(data, 10)? as usize;
+    let header = read_u32(data, 14)? as usize;
+
+    let (width, height, bpp, compression, colors_used) = if header == 12 {
+        (read_u16(data, 18)? as i32, read_u16(data, 20)? as i16 as i32,
+            read_u16(data, 24)?, 0, 0)
+    } else if header >= 40 {
+        (read_u32(data, 18)? as i32, read_u32(data, 22)? as i32,
+            read_u16(data, 28)?, read_u32(data, 30)?, read_u32(data, 46)? as usize)
+    } else {
+        return Err(Error::Decode("Unsupported BMP header."));
+    };
+
+    const BI_RGB: u32 = 0;
+    const BI_BITFIELDS: u32 = 3;
+    const BI_ALPHABITFIELDS: u32 = 6;
+
+    if width <= 0 || height == 0 {
+        return Err(Error::Decode("BMP image has no pixels."));
+    }
+    let top_down = height < 0;
+    let width = width as usize;
+    let height = height.unsigned_abs() as usize;
+
+    // Channel masks for 16 and 32 bits per pixel.
+    let (masks, explicit_alpha) = match compression {
+        BI_RGB => match bpp {
+            16 => ([0x7c00, 0x03e0, 0x001f, 0], false),
+            _ => ([0x00ff_0000, 0x0000_ff00, 0x0000_00ff, 0xff00_0000], false),
+        },
+        BI_BITFIELDS | BI_ALPHABITFIELDS if bpp == 16 || bpp == 32 => {
+            let at = 14 + 40;
+            let alpha = if compression == BI_ALPHABITFIELDS || header >= 56 {
+                read_u32(data, at + 12)?
+            } else {
+                0
+            };
+            ([read_u32(data, at)?, read_u32(data, at + 4)?, read_u32(data, at + 8)?, alpha],
+                alpha != 0)
+        },
+        _ => return Err(Error::Decode("Compressed BMP images are not supported.")),
+    };
+
+    let palette = match bpp {
+        1 | 4 | 8 => {
+            let entry = if header == 12 { 3 } else { 4 };
+            let count = if colors_used == 0 { 1 << bpp } else { colors_used.min(1 << bpp) };
+            let start = 14 + header;
+            let bytes = data.get(start..start + count * entry)
+                .ok_or(Error::Decode("Truncated BMP palette."))?;
+            bytes.chunks(entry).map(|c| [c[2], c[1], c[0]]).collect::<Vec<[u8; 3]>>()
+        },
+        16 | 24 | 32 => Vec::new(),
+        _ => return Err(Error::Decode("Unsupported BMP bit depth.")),
+    };
+
+    let stride = width.checked_mul(bpp as usize).map(|bits| bits.div_ceil(32) * 4)
+        .ok_or(Error::Decode("BMP image is too large."))?;
+    let end = stride.checked_mul(height).and_then(|size| size.checked_add(offset))
+        .ok_or(Error::Decode("BMP image is too large."))?;
+    let raster = data.get(offset..end).ok_or(Error::Decode("Truncated BMP raster."))?;
+
+    let size = width.checked_mul(height).and_then(|pixels| pixels.checked_mul(4))
+        .ok_or(Error::Decode("BMP image is too large."))?;
+    let mut pixels = vec![0u8; size];
+    for (row_index, row) in raster.chunks(stride).enumerate() {
+        let y = if top_down { row_index } else { height - 1 - row_index };
+        for x in 0..width {
+            let rgba = match bpp {
+                1 | 4 | 8 => {
+                    let bits = bpp as usize;
+                    let bit = x * bits;
+                    let index = (row[bit / 8] >> (8 - bits - bit % 8)) & ((1 << bits) - 1) as u8;
+                    let c = palette.get(index as usize)
+                        .ok_or(Error::Decode("BMP palette index out of range."))?;
+                    [c[0], c[1], c[2], 255]
+                },
+                24 => [row[x * 3 + 2], row[x * 3 + 1], row[x * 3], 255],
+                _ => {
+                    let px = if bpp == 16 {
+                        row[x * 2] as u32 | (row[x * 2 + 1] as u32) << 8
+                    } else {
+                        row[x * 4] as u32 | (row[x * 4 + 1] as u32) << 8 |
+                            (row[x * 4 + 2] as u32) << 16 | (row[x * 4 + 3] as u32) << 24
+                    };
+                    [channel(px, masks[0]), channel(px, masks[1]), channel(px, masks[2]),
+                        if masks[3] != 0 { channel(px, masks[3]) } else { 255 }]
+                },
+            };
+            pixels[(y * width + x) * 4..(y * width + x + 1) * 4].copy_from_slice(&rgba);
+        }
+    }
+
+    // The alpha byte of BI_RGB 32-bit images is often unused and left zero.
+    if bpp == 32 && !explicit_alpha && pixels.chunks(4).all(|p| p[3] == 0) {
+        for p in pixels.chunks_mut(4) {
+            p[3] = 255;
+        }
+    }
+
+    Ok(Image::new(width, height, Format::Rgba, pixels))
+}
+
+fn looks_like_xbm(data: &[u8]) -> bool {
+    let text = String::from_utf8_lossy(&data[..data.len().min(256)]).into_owned();
+    text.trim_start().starts_with("#define") || text.trim_start().starts_with("/*")
+}
+
+fn decode_xbm(data: &[u8]) -> Result<Image, Error> {
+    let text = ::std::str::from_utf8(data)
+        .map_err(|_| Error::Decode("XBM data is not valid UTF-8."))?;
+
+    let mut width = None;
+    let mut height = None;
+    for line in text.lines() {
+        let mut words = line.split_whitespace();
+        if words.next() != Some("#define") {
+            continue;
+        }
+        let (name, value) = match (words.next(), words.next()) {
+            (Some(n), Some(v)) => (n, v.parse::<usize>().ok()),
+            _ => continue,
+        };
+        if name.ends_with("_width") {
+            width = value;
+        } else if name.ends_with("_height") {
+            height = value;
+        }
+    }
+
+    let (width, height) = match (width, height) {
+        (Some(w), Some(h)) if w > 0 && h > 0 => (w, h),
+        _ => return Err(Error::Decode("Missing XBM width or height.")),
+    };
+
+    let open = text.find('{').ok_or(Error::Decode("Missing XBM bits."))?;
+    // X10 bitmaps store the bits as 16-bit shorts.
+    let short = text[..open].contains("short");
+    let close = text[open..].find('}').map(|i| open + i)
+        .ok_or(Error::Decode("Unterminated XBM bits."))?;
+
+    let mut bytes = Vec::new();
+    for token in text[open + 1..close].split(',') {
+        let token = token.trim();
+        if token.is_empty() {
+            continue;
+        }
+        let value = if token.starts_with("0x") || token.starts_with("0X") {
+            u32::from_str_radix(&token[2..], 16)
+        } else {
+            token.parse::<u32>()
+        }.map_err(|_| Error::Decode("Invalid number in XBM bits."))?;
+
+        bytes.push(value as u8);
+        if short {
+            bytes.push((value >> 8) as u8);
+        }
+    }
+
+    let stride = if short { width.div_ceil(16) * 2 } else { width.div_ceil(8) };
+    match stride.checked_mul(height) {
+        Some(size) if size <= bytes.len() => {},
+        Some(_) => return Err(Error::Decode("Truncated XBM bits.")),
+        None => return Err(Error::Decode("XBM image is too large.")),
+    }
+
+    let mut pixels = Vec::with_capacity(width * height);
+    for row in bytes.chunks(stride).take(height) {
+        for x in 0..width {
+            pixels.push(if row[x / 8] & (1 << (x % 8)) != 0 { 255 } else { 0 });
+        }
+    }
+
+    Ok(Image::new(width, height, Format::Luma, pixels))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Image;
+    use resample::Format;
+    use Error;
+
+    fn bmp(width: i32, height: i32, bpp: u16, raster: &[u8]) -> Vec<u8> {
+        let mut data = b"BM".to_vec();
+        for &field in [54 + raster.len() as u32, 0, 54, 40, width as u32, height as u32].iter() {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(&1u16.to_le_bytes());
+        data.extend_from_slice(&bpp.to_le_bytes());
+        data.extend_from_slice(&[0; 24]);
+        data.extend_from_slice(raster);
+        data
+    }
+
+    fn assert_decode_error(data: &[u8]) {
+        match Image::decode(data) {
+            Err(Error::Decode(_)) => {},
+            other => panic!("expected a decode error, got {:?}", other.map(|i| i.format())),
+        }
+    }
+
+    #[test]
+    fn decodes_netpbm() {
+        let pbm = Image::decode(b"P1 3 2\n1 0 1\n010").unwrap();
+        assert_eq!((pbm.width(), pbm.height(), pbm.format()), (3, 2, Format::Luma));
+        assert_eq!(pbm.data(), &[255, 0, 255, 0, 255, 0]);
+
+        let raw_pbm = Image::decode(b"P4 3 2\n\xa0\x40").unwrap();
+        assert_eq!(raw_pbm.data(), pbm.data());
+
+        let pgm = Image::decode(b"P2 2 1 4\n0 2").unwrap();
+        assert_eq!(pgm.data(), &[0, 128]);
+        assert_eq!(Image::decode(b"P5 2 1 255\n\x00\x80").unwrap().data(), &[0, 128]);
+
+        let ppm = Image::decode(b"P3 1 1 255\n1 2 3").unwrap();
+        assert_eq!((ppm.format(), ppm.data()), (Format::Rgb, &[1, 2, 3][..]));
+        assert_eq!(Image::decode(b"P6 1 1 65535\n\xff\xff\x00\x00\x80\x00").unwrap().data(),
+            &[255, 0, 128]);
+    }
+
+    #[test]
+    fn decodes_bmp() {
+        let data = bmp(2, -2, 24, &[0, 0, 255, 0, 255, 0, 0, 0, 255, 0, 0, 1, 2, 3, 0, 0]);
+        let image = Image::decode(&data).unwrap();
+        assert_eq!((image.width(), image.height(), image.format()), (2, 2, Format::Rgba));
+        assert_eq!(image.data(), &[255, 0, 0, 255, 0, 255, 0, 255,
+            0, 0, 255, 255, 3, 2, 1, 255][..]);
+    }
+
+    #[test]
+    fn decodes_xbm() {
+        let data = b"#define a_width 3\n#define a_height 2\n\
+            static char a_bits[] = { 0x05, 0x02 };\n";
+        let image = Image::decode(data).unwrap();
+        assert_eq!((image.width(), image.height(), image.format()), (3, 2, Format::Luma));
+        assert_eq!(image.data(), &[255, 0, 255, 0, 255, 0]);
+    }
+
+    #[test]
+    fn rejects_oversized_netpbm_before_allocating() {
+        assert_decode_error(b"P5 100000 100000 255\n\x00");
+        assert_decode_error(b"P6 100000 100000 65535\n\x00");
+        assert_decode_error(b"P4 100000 100000\n\x00");
+        assert_decode_error(b"P2 100000 100000 255\n0 0 0");
+        assert_decode_error(b"P3 4294967295 4294967295 255\n0");
+    }
+
+    #[test]
+    fn rejects_overflowing_bmp_sizes() {
+        assert_decode_error(&bmp(0x7fff_ffff, 0x7fff_ffff, 32, &[0; 16]));
+        assert_decode_error(&bmp(0x7fff_ffff, 1, 24, &[0; 16]));
+
+        let mut data = bmp(2, 2, 24, &[0; 16]);
+        data[10..14].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_decode_error(&data);
+    }
+
+    #[test]
+    fn rejects_overflowing_xbm_sizes() {
+        assert_decode_error(b"#define a_width 1099511627776\n#define a_height 1099511627776\n\
+            static char a_bits[] = { 0x00 };\n");
+        assert_decode_error(b"#define a_width 16\n#define a_height 2\n\
+            static char a_bits[] = { 0x00 };\n");
+    }
+}
@@ -0,0 +1,359 @@
+//! Pixel access to background buffers.
+//!
+//! The [Canvas](trait.Canvas.html) trait is the common interface used to draw onto
+//! backgrounds, regardless of their pixel format. It is implemented by
+//! [MonoCanvas](struct.MonoCanvas.html) and [ColorCanvas](struct.ColorCanvas.html), which
+//! borrow the raw buffers passed to
+//! [set_mono_background](../struct.Driver.html#method.set_mono_background) and
+//! [set_color_background](../struct.Driver.html#method.set_color_background), and by
+//! [PackedMonoBitmap](../packed/struct.PackedMonoBitmap.html).
+//!
+//! ```
+//! use logitech_lcd::canvas::{Canvas, ColorCanvas};
+//! use logitech_lcd::color::Argb;
+//! use logitech_lcd::{COLOR_WIDTH, COLOR_HEIGHT};
+//!
+//! let mut background = vec![0u8; COLOR_WIDTH * COLOR_HEIGHT * 4];
+//! {
+//!     let mut canvas = ColorCanvas::new(&mut background);
+//!     canvas.put_pixel(10, 20, Argb::opaque(255, 0, 0));
+//!     canvas.put_pixel(-1, 500, Argb::opaque(255, 0, 0)); // Clipped.
+//! }
+//! assert_eq!(&background[(20 * COLOR_WIDTH + 10) * 4..][..4], &[0, 0, 255, 255]);
+//! ```
+
+use color::Argb;
+use packed::PackedMonoBitmap;
+use {COLOR_WIDTH, COLOR_HEIGHT, MONO_WIDTH, MONO_HEIGHT};
+
+/// A rectangle in pixel coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rect {
+    /// Left edge.
+    pub x: usize,
+    /// Top edge.
+    pub y: usize,
+    /// Width in pixels.
+    pub width: usize,
+    /// Height in pixels.
+    pub height: usize,
+}
+
+impl Rect {
+    /// Creates a rectangle.
+    pub const fn new(x: usize, y: usize, width: usize, height: usize) -> Rect {
+        Rect { x, y, width, height }
+    }
+
+    /// Returns `true` if the point is inside the rectangle.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x >= self.x && x - self.x < self.width && y >= self.y && y - self.y < self.height
+    }
+
+    /// Returns `true` if the rectangle covers no pixels.
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Returns the overlap of two rectangles, empty if they don't overlap.
+    pub fn intersect(&self, other: &Rect) -> Rect {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = (self.x + self.width).min(other.x + other.width);
+        let bottom = (self.y + self.height).min(other.y + other.height);
+        if right <= x || bottom <= y {
+            Rect::new(x, y, 0, 0)
+        } else {
+            Rect::new(x, y, right - x, bottom - y)
+        }
+    }
+
+    /// Returns the smallest rectangle covering both rectangles. Empty rectangles are ignored.
+    pub fn union(&self, other: &Rect) -> Rect {
+        if self.is_empty() {
+            return *other;
+        }
+        if other.is_empty() {
+            return *self;
+        }
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let right = (self.x + self.width).max(other.x + other.width);
+        let bottom = (self.y + self.height).max(other.y + other.height);
+        Rect::new(x, y, right - x, bottom - y)
+    }
+}
+
+/// A drawing surface with a fixed size and pixel type.
+///
+/// Implementors provide bounds-checked access through [pixel](#tymethod.pixel) and
+/// [set_pixel](#tymethod.set_pixel). The provided methods take signed coordinates and
+/// silently clip anything outside the canvas.
+pub trait Canvas {
+    /// The pixel value, `bool` for monochrome and [Argb](../color/struct.Argb.html) for color.
+    type Pixel: Copy;
+
+    /// Width in pixels.
+    fn width(&self) -> usize;
+
+    /// Height in pixels.
+    fn height(&self) -> usize;
+
+    /// Reads a pixel.
+    ///
+    /// Panics:
+    /// - If the coordinate is outside the canvas.
+    ///
+    fn pixel(&self, x: usize, y: usize) -> Self::Pixel;
+
+    /// Writes a pixel.
+    ///
+    /// Panics:
+    /// - If the coordinate is outside the canvas.
+    ///
+    fn set_pixel(&mut self, x: usize, y: usize, pixel: Self::Pixel);
+
+    /// Composites a partially covered pixel, used for antialiasing.
+    ///
+    /// The default implementation writes the pixel if coverage >= 128, which is what
+    /// monochrome canvases want.
+    ///
+    /// Panics:
+    /// - If the coordinate is outside the canvas.
+    ///
+    fn blend_pixel(&mut self, x: usize, y: usize, pixel: Self::Pixel, coverage: u8) {
+        if coverage >= 128 {
+            self.set_pixel(x, y, pixel);
+        }
+    }
+
+    /// The area of the canvas that can be drawn to.
+    ///
+    /// Defaults to the whole canvas.
+    fn clip_rect(&self) -> Rect {
+        Rect::new(0, 0, self.width(), self.height())
+    }
+
+    /// Writes a pixel, ignoring coordinates outside the clip rect.
+    fn put_pixel(&mut self, x: isize, y: isize, pixel: Self::Pixel) {
+        if x >= 0 && y >= 0 && self.clip_rect().contains(x as usize, y as usize) {
+            self.set_pixel(x as usize, y as usize, pixel);
+        }
+    }
+
+    /// Composites a partially covered pixel, ignoring coordinates outside the clip rect.
+    fn put_blended(&mut self, x: isize, y: isize, pixel: Self::Pixel, coverage: u8) {
+        if x >= 0 && y >= 0 && self.clip_rect().contains(x as usize, y as usize) {
+            self.blend_pixel(x as usize, y as usize, pixel, coverage);
+        }
+    }
+
+    /// Fills the whole canvas with one pixel value.
+    fn clear(&mut self, pixel: Self::Pixel) {
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                self.set_pixel(x, y, pixel);
+            }
+        }
+    }
+
+    /// Restricts drawing to `rect`, intersected with the current clip rect.
+    fn clipped(&mut self, rect: Rect) -> Clipped<'_, Self> where Self: Sized {
+        let rect = rect.intersect(&self.clip_rect());
+        Clipped { canvas: self, rect }
+    }
+}
+
+/// A canvas with drawing restricted to a rectangle, see [Canvas::clipped](trait.Canvas.html#method.clipped).
+#[derive(Debug)]
+pub struct Clipped<'a, C: 'a> {
+    canvas: &'a mut C,
+    rect: Rect,
+}
+
+impl<'a, C: Canvas> Canvas for Clipped<'a, C> {
+    type Pixel = C::Pixel;
+
+    fn width(&self) -> usize {
+        self.canvas.width()
+    }
+
+    fn height(&self) -> usize {
+        self.canvas.height()
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> C::Pixel {
+        self.canvas.pixel(x, y)
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, pixel: C::Pixel) {
+        if self.rect.contains(x, y) {
+            self.canvas.set_pixel(x, y, pixel);
+        }
+    }
+
+    fn blend_pixel(&mut self, x: usize, y: usize, pixel: C::Pixel, coverage: u8) {
+        if self.rect.contains(x, y) {
+            self.canvas.blend_pixel(x, y, pixel, coverage);
+        }
+    }
+
+    fn clip_rect(&self) -> Rect {
+        self.rect
+    }
+}
+
+/// A canvas over a monochrome buffer with 8 bits per pixel.
+///
+/// Pixels are `true` when on. On pixels are written as 255 and off pixels as 0, and a
+/// pixel reads as on if its value is >= 128, like the LCD does.
+#[derive(Debug)]
+pub struct MonoCanvas<'a> {
+    buf: &'a mut [u8],
+    width: usize,
+    height: usize,
+}
+
+impl<'a> MonoCanvas<'a> {
+    /// Wraps a monochrome background, 160x43 bytes.
+    ///
+    /// Panics:
+    /// - If buf's length is not 160x43 bytes.
+    ///
+    pub fn new(buf: &'a mut [u8]) -> MonoCanvas<'a> {
+        MonoCanvas::with_size(buf, MONO_WIDTH, MONO_HEIGHT)
+    }
+
+    /// Wraps a monochrome buffer of any size.
+    ///
+    /// Panics:
+    /// - If buf's length is not `width * height` bytes.
+    ///
+    pub fn with_size(buf: &'a mut [u8], width: usize, height: usize) -> MonoCanvas<'a> {
+        assert_eq!(buf.len(), width * height);
+        MonoCanvas { buf, width, height }
+    }
+
+    /// The underlying buffer.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.buf
+    }
+}
+
+impl<'a> Canvas for MonoCanvas<'a> {
+    type Pixel = bool;
+
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> bool {
+        assert!(x < self.width && y < self.height);
+        self.buf[y * self.width + x] >= 128
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, on: bool) {
+        assert!(x < self.width && y < self.height);
+        self.buf[y * self.width + x] = if on { 255 } else { 0 };
+    }
+}
+
+/// A canvas over a color buffer, 4 bytes per pixel in BGRA order.
+#[derive(Debug)]
+pub struct ColorCanvas<'a> {
+    buf: &'a mut [u8],
+    width: usize,
+    height: usize,
+}
+
+impl<'a> ColorCanvas<'a> {
+    /// Wraps a color background, 320x240 pixels.
+    ///
+    /// Panics:
+    /// - If buf's length is not 320x240x4 bytes.
+    ///
+    pub fn new(buf: &'a mut [u8]) -> ColorCanvas<'a> {
+        ColorCanvas::with_size(buf, COLOR_WIDTH, COLOR_HEIGHT)
+    }
+
+    /// Wraps a BGRA buffer of any size.
+    ///
+    /// Panics:
+    /// - If buf's length is not `width * height * 4` bytes.
+    ///
+    pub fn with_size(buf: &'a mut [u8], width: usize, height: usize) -> ColorCanvas<'a> {
+        assert_eq!(buf.len(), width * height * 4);
+        ColorCanvas { buf, width, height }
+    }
+
+    /// The underlying buffer.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.buf
+    }
+}
+
+impl<'a> Canvas for ColorCanvas<'a> {
+    type Pixel = Argb;
+
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> Argb {
+        assert!(x < self.width && y < self.height);
+        let i = (y * self.width + x) * 4;
+        Argb::from_bgra(&self.buf[i..i + 4])
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, pixel: Argb) {
+        assert!(x < self.width && y < self.height);
+        let i = (y * self.width + x) * 4;
+        self.buf[i..i + 4].copy_from_slice(&pixel.to_bgra());
+    }
+
+    fn blend_pixel(&mut self, x: usize, y: usize, pixel: Argb, coverage: u8) {
+        let dst = self.pixel(x, y);
+        self.set_pixel(x, y, pixel.over(dst, coverage));
+    }
+
+    fn clear(&mut self, pixel: Argb) {
+        let bgra = pixel.to_bgra();
+        for px in self.buf.chunks_mut(4) {
+            px.copy_from_slice(&bgra);
+        }
+    }
+}
+
+impl Canvas for PackedMonoBitmap {
+    type Pixel = bool;
+
+    fn width(&self) -> usize {
+        PackedMonoBitmap::width(self)
+    }
+
+    fn height(&self) -> usize {
+        PackedMonoBitmap::height(self)
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> bool {
+        assert!(x < PackedMonoBitmap::width(self) && y < PackedMonoBitmap::height(self));
+        self.get(x, y)
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, on: bool) {
+        assert!(x < PackedMonoBitmap::width(self) && y < PackedMonoBitmap::height(self));
+        self.set(x, y, on);
+    }
+
+    fn clear(&mut self, on: bool) {
+        self.fill(on);
+    }
+}
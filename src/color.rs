@@ -0,0 +1,552 @@
+//! Color types for the color LCD.
+//!
+//! The color LCD background is stored as BGRA, 4 bytes per pixel with straight (not
+//! premultiplied) alpha. [Argb](struct.Argb.html) converts to and from that layout.
+//!
+//! Both color types parse from the CSS notations `#rgb` and `#rrggbb`, with `#rgba` and
+//! `#rrggbbaa` for [Argb](struct.Argb.html), and from the CSS named colors, so colors can
+//! be read from configuration files. They print in the same hex notation.
+//!
+//! ```
+//! use logitech_lcd::color::{Argb, Rgb};
+//!
+//! let accent: Rgb = "#ff8000".parse().unwrap();
+//! assert_eq!(accent, Rgb::new(255, 128, 0));
+//! assert_eq!("CornflowerBlue".parse::<Rgb>().unwrap(), Rgb::new(100, 149, 237));
+//! assert_eq!("#0008".parse::<Argb>().unwrap(), Argb::new(0x88, 0, 0, 0));
+//! assert_eq!(accent.to_string(), "#ff8000");
+//!
+//! let (h, s, v) = accent.to_hsv();
+//! assert_eq!(Rgb::from_hsv(h + 180.0, s, v), Rgb::new(0, 127, 255));
+//! assert_eq!(Rgb::new(0, 0, 0).lerp(Rgb::new(255, 255, 255), 0.5), Rgb::new(128, 128, 128));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use Error;
+
+/// An opaque 24-bit RGB color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb {
+    /// Red channel.
+    pub r: u8,
+    /// Green channel.
+    pub g: u8,
+    /// Blue channel.
+    pub b: u8,
+}
+
+impl Rgb {
+    /// Creates a color from its channels.
+    pub const fn new(r: u8, g: u8, b: u8) -> Rgb {
+        Rgb { r, g, b }
+    }
+
+    /// Looks up a CSS named color, ignoring case.
+    pub fn named(name: &str) -> Option<Rgb> {
+        let name = name.to_ascii_lowercase();
+        NAMED_COLORS.binary_search_by(|&(n, _)| n.cmp(&name[..]))
+            .ok()
+            .map(|i| NAMED_COLORS[i].1)
+    }
+
+    /// Adds an alpha channel.
+    pub fn with_alpha(&self, a: u8) -> Argb {
+        Argb::new(a, self.r, self.g, self.b)
+    }
+
+    /// Creates a color from hue, saturation and value.
+    ///
+    /// Parameters:
+    /// - h: Hue in degrees, wrapped to 0 to 360.
+    /// - s, v: Saturation and value, clamped to 0 to 1.
+    ///
+    pub fn from_hsv(h: f32, s: f32, v: f32) -> Rgb {
+        let (s, v) = (s.clamp(0.0, 1.0), v.clamp(0.0, 1.0));
+        let c = v * s;
+        from_hue(h, c, v - c)
+    }
+
+    /// Returns hue in degrees from 0 to 360, saturation and value from 0 to 1. Grays have
+    /// hue 0.
+    pub fn to_hsv(&self) -> (f32, f32, f32) {
+        let (max, min) = self.max_min();
+        let s = if max > 0.0 { (max - min) / max } else { 0.0 };
+        (self.hue(), s, max)
+    }
+
+    /// Creates a color from hue, saturation and lightness.
+    ///
+    /// Parameters:
+    /// - h: Hue in degrees, wrapped to 0 to 360.
+    /// - s, l: Saturation and lightness, clamped to 0 to 1.
+    ///
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Rgb {
+        let (s, l) = (s.clamp(0.0, 1.0), l.clamp(0.0, 1.0));
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        from_hue(h, c, l - c / 2.0)
+    }
+
+    /// Returns hue in degrees from 0 to 360, saturation and lightness from 0 to 1. Grays
+    /// have hue 0.
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let (max, min) = self.max_min();
+        let l = (max + min) / 2.0;
+        let s = match l > 0.0 && l < 1.0 {
+            true => (max - min) / (1.0 - (2.0 * l - 1.0).abs()),
+            false => 0.0,
+        };
+        (self.hue(), s, l)
+    }
+
+    /// Blends linearly from this color at `t` = 0 to `to` at `t` = 1. `t` is clamped to
+    /// 0 to 1.
+    pub fn lerp(&self, to: Rgb, t: f32) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Rgb::new(mix(self.r, to.r), mix(self.g, to.g), mix(self.b, to.b))
+    }
+
+    fn max_min(&self) -> (f32, f32) {
+        let max = self.r.max(self.g).max(self.b) as f32 / 255.0;
+        let min = self.r.min(self.g).min(self.b) as f32 / 255.0;
+        (max, min)
+    }
+
+    fn hue(&self) -> f32 {
+        let (r, g, b) = (self.r as f32, self.g as f32, self.b as f32);
+        let (max, min) = self.max_min();
+        let d = (max - min) * 255.0;
+        if d == 0.0 {
+            return 0.0;
+        }
+        let h = if max * 255.0 == r {
+            (g - b) / d
+        } else if max * 255.0 == g {
+            (b - r) / d + 2.0
+        } else {
+            (r - g) / d + 4.0
+        };
+        (h * 60.0).rem_euclid(360.0)
+    }
+}
+
+/// The color with hue `h`, chroma `c` and `m` added to every channel.
+fn from_hue(h: f32, c: f32, m: f32) -> Rgb {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let channel = |v: f32| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+    Rgb::new(channel(r), channel(g), channel(b))
+}
+
+/// Parses the digits of a `#` color into channels, expanding single digit channels.
+fn parse_hex(s: &str, lengths: &[usize]) -> Result<Vec<u8>, Error> {
+    let digits = &s[1..];
+    if !lengths.contains(&digits.len()) || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(Error::ParseColor("Malformed hex color."));
+    }
+
+    let short = digits.len() <= 4;
+    let channel = |i: usize| -> u8 {
+        match short {
+            true => u8::from_str_radix(&digits[i..i + 1], 16).unwrap() * 0x11,
+            false => u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).unwrap(),
+        }
+    };
+    let count = if short { digits.len() } else { digits.len() / 2 };
+    Ok((0..count).map(channel).collect())
+}
+
+impl FromStr for Rgb {
+    type Err = Error;
+
+    /// Parses `#rgb`, `#rrggbb` or a CSS color name.
+    fn from_str(s: &str) -> Result<Rgb, Error> {
+        let s = s.trim();
+        if s.starts_with('#') {
+            let c = parse_hex(s, &[3, 6])?;
+            return Ok(Rgb::new(c[0], c[1], c[2]));
+        }
+        Rgb::named(s).ok_or(Error::ParseColor("Unknown color name."))
+    }
+}
+
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// A 32-bit color with straight alpha.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Argb {
+    /// Alpha channel, 0 is fully transparent and 255 fully opaque.
+    pub a: u8,
+    /// Red channel.
+    pub r: u8,
+    /// Green channel.
+    pub g: u8,
+    /// Blue channel.
+    pub b: u8,
+}
+
+impl Argb {
+    /// Creates a color from its channels.
+    pub const fn new(a: u8, r: u8, g: u8, b: u8) -> Argb {
+        Argb { a, r, g, b }
+    }
+
+    /// Creates a fully opaque color.
+    pub const fn opaque(r: u8, g: u8, b: u8) -> Argb {
+        Argb { a: 255, r, g, b }
+    }
+
+    /// Reads a color from a BGRA pixel.
+    pub fn from_bgra(px: &[u8]) -> Argb {
+        Argb { a: px[3], r: px[2], g: px[1], b: px[0] }
+    }
+
+    /// Returns the color as a BGRA pixel, the layout of the color LCD background.
+    pub fn to_bgra(&self) -> [u8; 4] {
+        [self.b, self.g, self.r, self.a]
+    }
+
+    /// Blends linearly from this color at `t` = 0 to `to` at `t` = 1, with premultiplied
+    /// alpha so transparent colors don't tint the result. `t` is clamped to 0 to 1.
+    pub fn lerp(&self, to: Argb, t: f32) -> Argb {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        let (sa, da) = (self.a as f32, to.a as f32);
+        let a = mix(sa, da);
+        if a == 0.0 {
+            return Argb::default();
+        }
+        let channel = |s: u8, d: u8| {
+            (mix(s as f32 * sa, d as f32 * da) / a).round().clamp(0.0, 255.0) as u8
+        };
+        Argb {
+            a: a.round() as u8,
+            r: channel(self.r, to.r),
+            g: channel(self.g, to.g),
+            b: channel(self.b, to.b),
+        }
+    }
+
+    /// Drops the alpha channel.
+    pub fn rgb(&self) -> Rgb {
+        Rgb::new(self.r, self.g, self.b)
+    }
+
+    /// Composites this color over `dst` with the Porter-Duff source-over operator.
+    ///
+    /// Parameters:
+    /// - coverage: Additional opacity applied to this color, 255 for full coverage.
+    ///   Used for antialiased edges.
+    ///
+    pub fn over(&self, dst: Argb, coverage: u8) -> Argb {
+        let sa = self.a as u32 * coverage as u32;
+        if sa == 0 {
+            return dst;
+        }
+        if sa == 255 * 255 {
+            return *self;
+        }
+
+        // All terms are scaled by 255 * 255.
+        let da = dst.a as u32 * (255 * 255 - sa) / 255;
+        let oa = sa + da;
+        let mix = |s: u8, d: u8| ((s as u32 * sa + d as u32 * da + oa / 2) / oa) as u8;
+
+        Argb {
+            a: ((oa + 127) / 255) as u8,
+            r: mix(self.r, dst.r),
+            g: mix(self.g, dst.g),
+            b: mix(self.b, dst.b),
+        }
+    }
+}
+
+impl From<Rgb> for Argb {
+    fn from(c: Rgb) -> Argb {
+        Argb::opaque(c.r, c.g, c.b)
+    }
+}
+
+impl FromStr for Argb {
+    type Err = Error;
+
+    /// Parses `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`, a CSS color name or `transparent`.
+    fn from_str(s: &str) -> Result<Argb, Error> {
+        let s = s.trim();
+        if s.starts_with('#') {
+            let c = parse_hex(s, &[3, 4, 6, 8])?;
+            return Ok(Argb::new(c.get(3).cloned().unwrap_or(255), c[0], c[1], c[2]));
+        }
+        if s.eq_ignore_ascii_case("transparent") {
+            return Ok(Argb::default());
+        }
+        s.parse::<Rgb>().map(Argb::from)
+    }
+}
+
+impl fmt::Display for Argb {
+    /// Prints `#rrggbb` for opaque colors and `#rrggbbaa` otherwise.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.rgb())?;
+        if self.a != 255 {
+            write!(f, "{:02x}", self.a)?;
+        }
+        Ok(())
+    }
+}
+
+/// The CSS named colors, sorted by name.
+const NAMED_COLORS: [(&str, Rgb); 148] = [
+    ("aliceblue", Rgb::new(0xf0, 0xf8, 0xff)),
+    ("antiquewhite", Rgb::new(0xfa, 0xeb, 0xd7)),
+    ("aqua", Rgb::new(0x00, 0xff, 0xff)),
+    ("aquamarine", Rgb::new(0x7f, 0xff, 0xd4)),
+    ("azure", Rgb::new(0xf0, 0xff, 0xff)),
+    ("beige", Rgb::new(0xf5, 0xf5, 0xdc)),
+    ("bisque", Rgb::new(0xff, 0xe4, 0xc4)),
+    ("black", Rgb::new(0x00, 0x00, 0x00)),
+    ("blanchedalmond", Rgb::new(0xff, 0xeb, 0xcd)),
+    ("blue", Rgb::new(0x00, 0x00, 0xff)),
+    ("blueviolet", Rgb::new(0x8a, 0x2b, 0xe2)),
+    ("brown", Rgb::new(0xa5, 0x2a, 0x2a)),
+    ("burlywood", Rgb::new(0xde, 0xb8, 0x87)),
+    ("cadetblue", Rgb::new(0x5f, 0x9e, 0xa0)),
+    ("chartreuse", Rgb::new(0x7f, 0xff, 0x00)),
+    ("chocolate", Rgb::new(0xd2, 0x69, 0x1e)),
+    ("coral", Rgb::new(0xff, 0x7f, 0x50)),
+    ("cornflowerblue", Rgb::new(0x64, 0x95, 0xed)),
+    ("cornsilk", Rgb::new(0xff, 0xf8, 0xdc)),
+    ("crimson", Rgb::new(0xdc, 0x14, 0x3c)),
+    ("cyan", Rgb::new(0x00, 0xff, 0xff)),
+    ("darkblue", Rgb::new(0x00, 0x00, 0x8b)),
+    ("darkcyan", Rgb::new(0x00, 0x8b, 0x8b)),
+    ("darkgoldenrod", Rgb::new(0xb8, 0x86, 0x0b)),
+    ("darkgray", Rgb::new(0xa9, 0xa9, 0xa9)),
+    ("darkgreen", Rgb::new(0x00, 0x64, 0x00)),
+    ("darkgrey", Rgb::new(0xa9, 0xa9, 0xa9)),
+    ("darkkhaki", Rgb::new(0xbd, 0xb7, 0x6b)),
+    ("darkmagenta", Rgb::new(0x8b, 0x00, 0x8b)),
+    ("darkolivegreen", Rgb::new(0x55, 0x6b, 0x2f)),
+    ("darkorange", Rgb::new(0xff, 0x8c, 0x00)),
+    ("darkorchid", Rgb::new(0x99, 0x32, 0xcc)),
+    ("darkred", Rgb::new(0x8b, 0x00, 0x00)),
+    ("darksalmon", Rgb::new(0xe9, 0x96, 0x7a)),
+    ("darkseagreen", Rgb::new(0x8f, 0xbc, 0x8f)),
+    ("darkslateblue", Rgb::new(0x48, 0x3d, 0x8b)),
+    ("darkslategray", Rgb::new(0x2f, 0x4f, 0x4f)),
+    ("darkslategrey", Rgb::new(0x2f, 0x4f, 0x4f)),
+    ("darkturquoise", Rgb::new(0x00, 0xce, 0xd1)),
+    ("darkviolet", Rgb::new(0x94, 0x00, 0xd3)),
+    ("deeppink", Rgb::new(0xff, 0x14, 0x93)),
+    ("deepskyblue", Rgb::new(0x00, 0xbf, 0xff)),
+    ("dimgray", Rgb::new(0x69, 0x69, 0x69)),
+    ("dimgrey", Rgb::new(0x69, 0x69, 0x69)),
+    ("dodgerblue", Rgb::new(0x1e, 0x90, 0xff)),
+    ("firebrick", Rgb::new(0xb2, 0x22, 0x22)),
+    ("floralwhite", Rgb::new(0xff, 0xfa, 0xf0)),
+    ("forestgreen", Rgb::new(0x22, 0x8b, 0x22)),
+    ("fuchsia", Rgb::new(0xff, 0x00, 0xff)),
+    ("gainsboro", Rgb::new(0xdc, 0xdc, 0xdc)),
+    ("ghostwhite", Rgb::new(0xf8, 0xf8, 0xff)),
+    ("gold", Rgb::new(0xff, 0xd7, 0x00)),
+    ("goldenrod", Rgb::new(0xda, 0xa5, 0x20)),
+    ("gray", Rgb::new(0x80, 0x80, 0x80)),
+    ("green", Rgb::new(0x00, 0x80, 0x00)),
+    ("greenyellow", Rgb::new(0xad, 0xff, 0x2f)),
+    ("grey", Rgb::new(0x80, 0x80, 0x80)),
+    ("honeydew", Rgb::new(0xf0, 0xff, 0xf0)),
+    ("hotpink", Rgb::new(0xff, 0x69, 0xb4)),
+    ("indianred", Rgb::new(0xcd, 0x5c, 0x5c)),
+    ("indigo", Rgb::new(0x4b, 0x00, 0x82)),
+    ("ivory", Rgb::new(0xff, 0xff, 0xf0)),
+    ("khaki", Rgb::new(0xf0, 0xe6, 0x8c)),
+    ("lavender", Rgb::new(0xe6, 0xe6, 0xfa)),
+    ("lavenderblush", Rgb::new(0xff, 0xf0, 0xf5)),
+    ("lawngreen", Rgb::new(0x7c, 0xfc, 0x00)),
+    ("lemonchiffon", Rgb::new(0xff, 0xfa, 0xcd)),
+    ("lightblue", Rgb::new(0xad, 0xd8, 0xe6)),
+    ("lightcoral", Rgb::new(0xf0, 0x80, 0x80)),
+    ("lightcyan", Rgb::new(0xe0, 0xff, 0xff)),
+    ("lightgoldenrodyellow", Rgb::new(0xfa, 0xfa, 0xd2)),
+    ("lightgray", Rgb::new(0xd3, 0xd3, 0xd3)),
+    ("lightgreen", Rgb::new(0x90, 0xee, 0x90)),
+    ("lightgrey", Rgb::new(0xd3, 0xd3, 0xd3)),
+    ("lightpink", Rgb::new(0xff, 0xb6, 0xc1)),
+    ("lightsalmon", Rgb::new(0xff, 0xa0, 0x7a)),
+    ("lightseagreen", Rgb::new(0x20, 0xb2, 0xaa)),
+    ("lightskyblue", Rgb::new(0x87, 0xce, 0xfa)),
+    ("lightslategray", Rgb::new(0x77, 0x88, 0x99)),
+    ("lightslategrey", Rgb::new(0x77, 0x88, 0x99)),
+    ("lightsteelblue", Rgb::new(0xb0, 0xc4, 0xde)),
+    ("lightyellow", Rgb::new(0xff, 0xff, 0xe0)),
+    ("lime", Rgb::new(0x00, 0xff, 0x00)),
+    ("limegreen", Rgb::new(0x32, 0xcd, 0x32)),
+    ("linen", Rgb::new(0xfa, 0xf0, 0xe6)),
+    ("magenta", Rgb::new(0xff, 0x00, 0xff)),
+    ("maroon", Rgb::new(0x80, 0x00, 0x00)),
+    ("mediumaquamarine", Rgb::new(0x66, 0xcd, 0xaa)),
+    ("mediumblue", Rgb::new(0x00, 0x00, 0xcd)),
+    ("mediumorchid", Rgb::new(0xba, 0x55, 0xd3)),
+    ("mediumpurple", Rgb::new(0x93, 0x70, 0xdb)),
+    ("mediumseagreen", Rgb::new(0x3c, 0xb3, 0x71)),
+    ("mediumslateblue", Rgb::new(0x7b, 0x68, 0xee)),
+    ("mediumspringgreen", Rgb::new(0x00, 0xfa, 0x9a)),
+    ("mediumturquoise", Rgb::new(0x48, 0xd1, 0xcc)),
+    ("mediumvioletred", Rgb::new(0xc7, 0x15, 0x85)),
+    ("midnightblue", Rgb::new(0x19, 0x19, 0x70)),
+    ("mintcream", Rgb::new(0xf5, 0xff, 0xfa)),
+    ("mistyrose", Rgb::new(0xff, 0xe4, 0xe1)),
+    ("moccasin", Rgb::new(0xff, 0xe4, 0xb5)),
+    ("navajowhite", Rgb::new(0xff, 0xde, 0xad)),
+    ("navy", Rgb::new(0x00, 0x00, 0x80)),
+    ("oldlace", Rgb::new(0xfd, 0xf5, 0xe6)),
+    ("olive", Rgb::new(0x80, 0x80, 0x00)),
+    ("olivedrab", Rgb::new(0x6b, 0x8e, 0x23)),
+    ("orange", Rgb::new(0xff, 0xa5, 0x00)),
+    ("orangered", Rgb::new(0xff, 0x45, 0x00)),
+    ("orchid", Rgb::new(0xda, 0x70, 0xd6)),
+    ("palegoldenrod", Rgb::new(0xee, 0xe8, 0xaa)),
+    ("palegreen", Rgb::new(0x98, 0xfb, 0x98)),
+    ("paleturquoise", Rgb::new(0xaf, 0xee, 0xee)),
+    ("palevioletred", Rgb::new(0xdb, 0x70, 0x93)),
+    ("papayawhip", Rgb::new(0xff, 0xef, 0xd5)),
+    ("peachpuff", Rgb::new(0xff, 0xda, 0xb9)),
+    ("peru", Rgb::new(0xcd, 0x85, 0x3f)),
+    ("pink", Rgb::new(0xff, 0xc0, 0xcb)),
+    ("plum", Rgb::new(0xdd, 0xa0, 0xdd)),
+    ("powderblue", Rgb::new(0xb0, 0xe0, 0xe6)),
+    ("purple", Rgb::new(0x80, 0x00, 0x80)),
+    ("rebeccapurple", Rgb::new(0x66, 0x33, 0x99)),
+    ("red", Rgb::new(0xff, 0x00, 0x00)),
+    ("rosybrown", Rgb::new(0xbc, 0x8f, 0x8f)),
+    ("royalblue", Rgb::new(0x41, 0x69, 0xe1)),
+    ("saddlebrown", Rgb::new(0x8b, 0x45, 0x13)),
+    ("salmon", Rgb::new(0xfa, 0x80, 0x72)),
+    ("sandybrown", Rgb::new(0xf4, 0xa4, 0x60)),
+    ("seagreen", Rgb::new(0x2e, 0x8b, 0x57)),
+    ("seashell", Rgb::new(0xff, 0xf5, 0xee)),
+    ("sienna", Rgb::new(0xa0, 0x52, 0x2d)),
+    ("silver", Rgb::new(0xc0, 0xc0, 0xc0)),
+    ("skyblue", Rgb::new(0x87, 0xce, 0xeb)),
+    ("slateblue", Rgb::new(0x6a, 0x5a, 0xcd)),
+    ("slategray", Rgb::new(0x70, 0x80, 0x90)),
+    ("slategrey", Rgb::new(0x70, 0x80, 0x90)),
+    ("snow", Rgb::new(0xff, 0xfa, 0xfa)),
+    ("springgreen", Rgb::new(0x00, 0xff, 0x7f)),
+    ("steelblue", Rgb::new(0x46, 0x82, 0xb4)),
+    ("tan", Rgb::new(0xd2, 0xb4, 0x8c)),
+    ("teal", Rgb::new(0x00, 0x80, 0x80)),
+    ("thistle", Rgb::new(0xd8, 0xbf, 0xd8)),
+    ("tomato", Rgb::new(0xff, 0x63, 0x47)),
+    ("turquoise", Rgb::new(0x40, 0xe0, 0xd0)),
+    ("violet", Rgb::new(0xee, 0x82, 0xee)),
+    ("wheat", Rgb::new(0xf5, 0xde, 0xb3)),
+    ("white", Rgb::new(0xff, 0xff, 0xff)),
+    ("whitesmoke", Rgb::new(0xf5, 0xf5, 0xf5)),
+    ("yellow", Rgb::new(0xff, 0xff, 0x00)),
+    ("yellowgreen", Rgb::new(0x9a, 0xcd, 0x32)),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{Argb, Rgb, NAMED_COLORS};
+    use Error;
+
+    #[test]
+    fn parses_hex() {
+        assert_eq!("#f80".parse::<Rgb>().unwrap(), Rgb::new(0xff, 0x88, 0x00));
+        assert_eq!("#12ab9F".parse::<Rgb>().unwrap(), Rgb::new(0x12, 0xab, 0x9f));
+        assert_eq!(" #000000 ".parse::<Rgb>().unwrap(), Rgb::new(0, 0, 0));
+        assert_eq!("#f80".parse::<Argb>().unwrap(), Argb::new(0xff, 0xff, 0x88, 0x00));
+        assert_eq!("#f808".parse::<Argb>().unwrap(), Argb::new(0x88, 0xff, 0x88, 0x00));
+        assert_eq!("#12345678".parse::<Argb>().unwrap(), Argb::new(0x78, 0x12, 0x34, 0x56));
+    }
+
+    #[test]
+    fn rejects_malformed_hex() {
+        for s in ["#", "#12", "#12345", "#1234567", "#123456789", "#ggg", "#12 456", "#f808"] {
+            let err = s.parse::<Rgb>().unwrap_err();
+            assert!(matches!(err, Error::ParseColor("Malformed hex color.")), "{}", s);
+        }
+        for s in ["#", "#12", "#12345", "#1234567", "#xyz"] {
+            assert!(matches!(s.parse::<Argb>(), Err(Error::ParseColor(_))), "{}", s);
+        }
+    }
+
+    #[test]
+    fn looks_up_names() {
+        assert_eq!(Rgb::named("red"), Some(Rgb::new(255, 0, 0)));
+        assert_eq!(Rgb::named("ReBeCcApUrPlE"), Some(Rgb::new(0x66, 0x33, 0x99)));
+        assert_eq!(Rgb::named("grey"), Rgb::named("gray"));
+        assert_eq!(Rgb::named("no such color"), None);
+        assert!(matches!("blurple".parse::<Rgb>(), Err(Error::ParseColor("Unknown color name."))));
+        assert_eq!("transparent".parse::<Argb>().unwrap(), Argb::new(0, 0, 0, 0));
+        assert!("transparent".parse::<Rgb>().is_err());
+        assert!(NAMED_COLORS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn prints_hex() {
+        assert_eq!(Rgb::new(1, 0xab, 255).to_string(), "#01abff");
+        assert_eq!(Argb::new(255, 1, 2, 3).to_string(), "#010203");
+        assert_eq!(Argb::new(0x80, 1, 2, 3).to_string(), "#01020380");
+        let c = Argb::new(0x40, 0x12, 0x34, 0x56);
+        assert_eq!(c.to_string().parse::<Argb>().unwrap(), c);
+    }
+
+    #[test]
+    fn hsv_and_hsl_round_trip() {
+        let colors = [Rgb::new(255, 0, 0), Rgb::new(255, 0, 1), Rgb::new(0, 200, 100),
+            Rgb::new(17, 34, 51), Rgb::new(128, 128, 128), Rgb::new(255, 255, 255)];
+        for &c in colors.iter() {
+            let (h, s, v) = c.to_hsv();
+            assert!((0.0..360.0).contains(&h), "{} {}", c, h);
+            assert_eq!(Rgb::from_hsv(h, s, v), c);
+            let (h, s, l) = c.to_hsl();
+            assert!((0.0..360.0).contains(&h), "{} {}", c, h);
+            assert_eq!(Rgb::from_hsl(h, s, l), c);
+        }
+    }
+
+    #[test]
+    fn hue_wraps_at_360() {
+        let red = Rgb::new(255, 0, 0);
+        assert_eq!(red.to_hsv(), (0.0, 1.0, 1.0));
+        assert_eq!(Rgb::from_hsv(0.0, 1.0, 1.0), red);
+        assert_eq!(Rgb::from_hsv(360.0, 1.0, 1.0), red);
+        assert_eq!(Rgb::from_hsv(-120.0, 1.0, 1.0), Rgb::from_hsv(240.0, 1.0, 1.0));
+        assert_eq!(Rgb::from_hsl(360.0, 1.0, 0.5), red);
+        assert_eq!(Rgb::from_hsl(720.0 + 120.0, 1.0, 0.5), Rgb::new(0, 255, 0));
+        // Just below red the other way round the circle.
+        let (h, _, _) = Rgb::new(255, 0, 1).to_hsv();
+        assert!(h > 359.0 && h < 360.0);
+    }
+
+    #[test]
+    fn lerp_clamps_t() {
+        let (a, b) = (Rgb::new(0, 100, 200), Rgb::new(200, 100, 0));
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.25), Rgb::new(50, 100, 150));
+        assert_eq!(a.lerp(b, -1.0), a);
+        assert_eq!(a.lerp(b, 2.0), b);
+        assert_eq!(a.lerp(b, f32::INFINITY), b);
+        let (a, b) = (Argb::new(0, 0, 0, 0), Argb::new(255, 255, 255, 255));
+        assert_eq!(a.lerp(b, -0.5), a);
+        assert_eq!(a.lerp(b, 1.5), b);
+    }
+}
@@ -0,0 +1,161 @@
+//! Dithering of grayscale frames for the monochrome LCD.
+//!
+//! The monochrome LCD turns a pixel on if its byte is >= 128 and off otherwise, so
+//! grayscale art loses all shading when uploaded as is. Dithering trades resolution for
+//! tone by spreading on pixels proportionally to the brightness of the area.
+//!
+//! ```
+//! use logitech_lcd::dither::{self, Dither};
+//! use logitech_lcd::{MONO_WIDTH, MONO_HEIGHT};
+//!
+//! // Horizontal gradient from black to white.
+//! let mut background = (0..MONO_WIDTH * MONO_HEIGHT)
+//!     .map(|i| ((i % MONO_WIDTH) * 255 / (MONO_WIDTH - 1)) as u8)
+//!     .collect::<Vec<u8>>();
+//!
+//! dither::dither(&mut background, MONO_WIDTH, MONO_HEIGHT, Dither::Bayer);
+//! assert!(background.iter().all(|&p| p == 0 || p == 255));
+//! ```
+
+/// Dithering method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dither {
+    /// Fixed threshold at 128, no dithering.
+    Threshold,
+    /// Ordered dithering with an 8x8 Bayer matrix. Stable between frames, which makes it
+    /// the best choice for animations.
+    Bayer,
+    /// Floyd-Steinberg error diffusion.
+    FloydSteinberg,
+    /// Atkinson error diffusion, only diffuses 3/4 of the error which keeps more contrast.
+    Atkinson,
+}
+
+/// 8x8 Bayer index matrix.
+const BAYER_8X8: [[u8; 8]; 8] = [
+    [ 0, 32,  8, 40,  2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44,  4, 36, 14, 46,  6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [ 3, 35, 11, 43,  1, 33,  9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47,  7, 39, 13, 45,  5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+/// Dithers a grayscale frame in place, leaving only the values 0 and 255.
+///
+/// Parameters:
+/// - luma: 8-bit grayscale frame, `width * height` bytes, row-major.
+///
+/// Panics:
+/// - If luma's length is not `width * height` bytes.
+///
+pub fn dither(luma: &mut [u8], width: usize, height: usize, method: Dither) {
+    assert_eq!(luma.len(), width * height);
+
+    match method {
+        Dither::Threshold => threshold(luma),
+        Dither::Bayer => bayer(luma, width),
+        Dither::FloydSteinberg => diffuse(luma, width, height, &FLOYD_STEINBERG, 16),
+        Dither::Atkinson => diffuse(luma, width, height, &ATKINSON, 8),
+    }
+}
+
+fn threshold(luma: &mut [u8]) {
+    for l in luma.iter_mut() {
+        *l = if *l >= 128 { 255 } else { 0 };
+    }
+}
+
+fn bayer(luma: &mut [u8], width: usize) {
+    for (i, l) in luma.iter_mut().enumerate() {
+        let m = BAYER_8X8[(i / width) % 8][(i % width) % 8] as u32;
+        // On if l / 255 > (m + 0.5) / 64.
+        *l = if *l as u32 * 128 > (2 * m + 1) * 255 { 255 } else { 0 };
+    }
+}
+
+/// Error diffusion weights as (dx, dy, weight).
+const FLOYD_STEINBERG: [(isize, usize, i32); 4] = [
+    (1, 0, 7), (-1, 1, 3), (0, 1, 5), (1, 1, 1),
+];
+
+const ATKINSON: [(isize, usize, i32); 6] = [
+    (1, 0, 1), (2, 0, 1), (-1, 1, 1), (0, 1, 1), (1, 1, 1), (0, 2, 1),
+];
+
+fn diffuse(luma: &mut [u8], width: usize, height: usize, weights: &[(isize, usize, i32)],
+    divisor: i32)
+{
+    let mut values = luma.iter().map(|&l| l as i32).collect::<Vec<i32>>();
+
+    for y in 0..height {
+        for x in 0..width {
+            let old = values[y * width + x];
+            let new = if old >= 128 { 255 } else { 0 };
+            let error = old - new;
+            luma[y * width + x] = new as u8;
+
+            for &(dx, dy, w) in weights {
+                let nx = x as isize + dx;
+                let ny = y + dy;
+                if nx >= 0 && (nx as usize) < width && ny < height {
+                    values[ny * width + nx as usize] += error * w / divisor;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dither, Dither};
+
+    const METHODS: [Dither; 4] =
+        [Dither::Threshold, Dither::Bayer, Dither::FloydSteinberg, Dither::Atkinson];
+
+    fn dithered(mut luma: Vec<u8>, width: usize, height: usize, method: Dither) -> Vec<u8> {
+        dither(&mut luma, width, height, method);
+        luma
+    }
+
+    #[test]
+    fn keeps_black_and_white() {
+        for &method in METHODS.iter() {
+            assert_eq!(dithered(vec![0; 64], 8, 8, method), vec![0; 64]);
+            assert_eq!(dithered(vec![255; 64], 8, 8, method), vec![255; 64]);
+        }
+    }
+
+    #[test]
+    fn thresholds_at_128() {
+        assert_eq!(dithered(vec![0, 127, 128, 255], 4, 1, Dither::Threshold), [0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn mid_gray_turns_on_about_half_the_pixels() {
+        for &method in METHODS[1..].iter() {
+            let on = dithered(vec![128; 32 * 32], 32, 32, method).iter()
+                .filter(|&&p| p == 255).count();
+            assert!((32 * 32 * 2 / 5..=32 * 32 * 3 / 5).contains(&on), "{:?}: {}", method, on);
+        }
+    }
+
+    #[test]
+    fn dithers_single_pixels_and_lines() {
+        for &method in METHODS.iter() {
+            assert_eq!(dithered(vec![0], 1, 1, method), [0]);
+            assert_eq!(dithered(vec![255], 1, 1, method), [255]);
+            assert!([0, 255].contains(&dithered(vec![50], 1, 1, method)[0]));
+
+            let row = dithered((0..=255).collect(), 256, 1, method);
+            let column = dithered((0..=255).collect(), 1, 256, method);
+            for out in [row, column].iter() {
+                assert!(out.iter().all(|&p| p == 0 || p == 255));
+                assert_eq!(out[0], 0);
+                assert_eq!(out[255], 255);
+            }
+        }
+    }
+}
@@ -0,0 +1,453 @@
+//! 2D drawing primitives.
+//!
+//! Lines, rectangles, rounded rectangles, circles, arcs and polygons, drawn onto any
+//! [Canvas](../canvas/trait.Canvas.html). The same functions draw onto monochrome and color
+//! backgrounds, the pixel type of the canvas decides the color type of the
+//! [Style](struct.Style.html).
+//!
+//! Lines use Bresenham's algorithm and curves the midpoint circle algorithm, so shapes are
+//! pixel exact and identical on both LCDs. Everything outside the canvas' clip rect is
+//! clipped, coordinates may be negative or far outside the screen.
+//!
+//! [highlight_line](fn.highlight_line.html) and [invert_line](fn.invert_line.html) draw a
+//! bar behind one of the SDK's text lines, to mark a selected line.
+//!
+//! ```
+//! use logitech_lcd::canvas::{ColorCanvas, MonoCanvas};
+//! use logitech_lcd::color::Argb;
+//! use logitech_lcd::draw::{self, Style};
+//! use logitech_lcd::{COLOR_WIDTH, COLOR_HEIGHT, MONO_WIDTH, MONO_HEIGHT};
+//!
+//! let mut mono = vec![0u8; MONO_WIDTH * MONO_HEIGHT];
+//! {
+//!     let mut canvas = MonoCanvas::new(&mut mono);
+//!     draw::rounded_rect(&mut canvas, 0, 0, 160, 43, 5, &Style::stroke(true));
+//!     draw::line(&mut canvas, (10, 30), (150, 12), true, 1);
+//! }
+//! assert_eq!(mono[MONO_WIDTH * 21], 255);
+//!
+//! let mut color = vec![0u8; COLOR_WIDTH * COLOR_HEIGHT * 4];
+//! {
+//!     let mut canvas = ColorCanvas::new(&mut color);
+//!     let style = Style::fill_and_stroke(Argb::opaque(0, 0, 128), Argb::opaque(255, 255, 255))
+//!         .with_stroke_width(3);
+//!     draw::circle(&mut canvas, 160, 120, 100, &style);
+//! }
+//! assert_eq!(&color[(120 * COLOR_WIDTH + 160) * 4..][..4], &[128, 0, 0, 255]);
+//! ```
+
+use canvas::Canvas;
+use color::Argb;
+use text::{LineRects, TextLine};
+
+/// How a closed shape is painted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Style<P> {
+    /// Color of the interior, `None` to leave it untouched.
+    pub fill: Option<P>,
+    /// Color of the outline, `None` for no outline.
+    pub stroke: Option<P>,
+    /// Width of the outline in pixels. The outline is drawn inside the shape's bounds.
+    pub stroke_width: usize,
+}
+
+impl<P: Copy> Style<P> {
+    /// Fills the shape without an outline.
+    pub fn fill(color: P) -> Style<P> {
+        Style { fill: Some(color), stroke: None, stroke_width: 0 }
+    }
+
+    /// Draws a 1 pixel outline without filling the shape.
+    pub fn stroke(color: P) -> Style<P> {
+        Style { fill: None, stroke: Some(color), stroke_width: 1 }
+    }
+
+    /// Fills the shape and draws a 1 pixel outline on top.
+    pub fn fill_and_stroke(fill: P, stroke: P) -> Style<P> {
+        Style { fill: Some(fill), stroke: Some(stroke), stroke_width: 1 }
+    }
+
+    /// Changes the width of the outline.
+    pub fn with_stroke_width(mut self, width: usize) -> Style<P> {
+        self.stroke_width = width;
+        self
+    }
+}
+
+/// Draws a straight line between two points, both included.
+///
+/// Parameters:
+/// - width: Line width in pixels. Wide lines are drawn with a square brush centered on
+///   the line.
+///
+pub fn line<C: Canvas>(canvas: &mut C, from: (isize, isize), to: (isize, isize),
+    color: C::Pixel, width: usize)
+{
+    if width == 0 {
+        return;
+    }
+    let offset = (width as isize - 1) / 2;
+    let (from, to) = match clip_line(canvas, from, to, width as isize) {
+        Some(line) => line,
+        None => return,
+    };
+
+    let (mut x, mut y) = from;
+    let dx = (to.0 - x).abs();
+    let dy = -(to.1 - y).abs();
+    let sx = if x < to.0 { 1 } else { -1 };
+    let sy = if y < to.1 { 1 } else { -1 };
+    let mut err = dx + dy;
+
+    loop {
+        if width == 1 {
+            canvas.put_pixel(x, y, color);
+        } else {
+            for by in 0..width as isize {
+                span(canvas, x - offset, x - offset + width as isize - 1, y - offset + by, color);
+            }
+        }
+        if x == to.0 && y == to.1 {
+            break;
+        }
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+    }
+}
+
+/// Draws connected line segments through the points.
+pub fn polyline<C: Canvas>(canvas: &mut C, points: &[(isize, isize)], color: C::Pixel,
+    width: usize)
+{
+    for pair in points.windows(2) {
+        line(canvas, pair[0], pair[1], color, width);
+    }
+}
+
+/// Draws a rectangle with its top left corner at (x, y).
+pub fn rect<C: Canvas>(canvas: &mut C, x: isize, y: isize, width: usize, height: usize,
+    style: &Style<C::Pixel>)
+{
+    rounded_rect(canvas, x, y, width, height, 0, style);
+}
+
+/// Draws a rectangle with rounded corners.
+///
+/// Parameters:
+/// - radius: Corner radius in pixels, limited to half the shorter side.
+///
+pub fn rounded_rect<C: Canvas>(canvas: &mut C, x: isize, y: isize, width: usize,
+    height: usize, radius: usize, style: &Style<C::Pixel>)
+{
+    let outer = RoundedBox::new(x, width, height, radius);
+    let sw = stroke_width(style);
+    let inner = if sw == 0 {
+        Some(outer)
+    } else if width > 2 * sw && height > 2 * sw {
+        Some(RoundedBox::new(x + sw as isize, width - 2 * sw, height - 2 * sw,
+            outer.radius.saturating_sub(sw)))
+    } else {
+        None
+    };
+
+    for row in 0..height {
+        let py = y + row as isize;
+        if !row_visible(canvas, py) {
+            continue;
+        }
+        let (left, right) = outer.span(row);
+        let inner_span = inner.and_then(|inner| {
+            if row >= sw && row - sw < inner.height { Some(inner.span(row - sw)) } else { None }
+        });
+
+        match (inner_span, style.stroke) {
+            (Some((il, ir)), Some(stroke)) if sw > 0 => {
+                if let Some(fill) = style.fill {
+                    span(canvas, il, ir, py, fill);
+                }
+                span(canvas, left, il - 1, py, stroke);
+                span(canvas, ir + 1, right, py, stroke);
+            },
+            (Some((il, ir)), _) => {
+                if let Some(fill) = style.fill {
+                    span(canvas, il, ir, py, fill);
+                }
+            },
+            (None, Some(stroke)) => span(canvas, left, right, py, stroke),
+            (None, None) => {},
+        }
+    }
+}
+
+/// Draws a circle centered on (cx, cy). The circle is `2 * radius + 1` pixels wide.
+pub fn circle<C: Canvas>(canvas: &mut C, cx: isize, cy: isize, radius: usize,
+    style: &Style<C::Pixel>)
+{
+    let r = radius as isize;
+    rounded_rect(canvas, cx - r, cy - r, 2 * radius + 1, 2 * radius + 1, radius, style);
+}
+
+/// Draws part of a circle centered on (cx, cy).
+///
+/// The stroke follows the curved edge only, the fill paints a pie slice.
+///
+/// Parameters:
+/// - start: Start angle in degrees, 0 is 3 o'clock and angles increase clockwise.
+/// - sweep: Angle covered by the arc in degrees, negative values go counterclockwise.
+///
+pub fn arc<C: Canvas>(canvas: &mut C, cx: isize, cy: isize, radius: usize, start: f32,
+    sweep: f32, style: &Style<C::Pixel>)
+{
+    let (start, sweep) = if sweep < 0.0 { (start + sweep, -sweep) } else { (start, sweep) };
+    let sw = stroke_width(style);
+    let inner = if sw <= radius { Some(radius - sw) } else { None };
+    let r = radius as isize;
+
+    for dy in -r..=r {
+        if !row_visible(canvas, cy + dy) {
+            continue;
+        }
+        let outer_half = half_width(radius, dy.unsigned_abs()) as isize;
+
+        for dx in -outer_half..=outer_half {
+            let color = if is_ring_edge(inner, dx, dy) { style.stroke } else { style.fill };
+            let color = match color {
+                Some(color) => color,
+                None => continue,
+            };
+            if in_sweep(dx, dy, start, sweep) {
+                canvas.put_pixel(cx + dx, cy + dy, color);
+            }
+        }
+    }
+}
+
+/// Draws a closed polygon.
+///
+/// The interior is filled with the even-odd rule, a pixel is inside if its coordinate is.
+/// The stroke connects the points and closes the shape.
+pub fn polygon<C: Canvas>(canvas: &mut C, points: &[(isize, isize)], style: &Style<C::Pixel>) {
+    if points.is_empty() {
+        return;
+    }
+
+    if let Some(fill) = style.fill {
+        let clip = canvas.clip_rect();
+        let top = points.iter().map(|p| p.1).min().unwrap().max(clip.y as isize);
+        let bottom = points.iter().map(|p| p.1).max().unwrap()
+            .min((clip.y + clip.height) as isize - 1);
+        let mut crossings = Vec::new();
+
+        for y in top..=bottom {
+            crossings.clear();
+            let yc = y as f64;
+            for (i, &(x0, y0)) in points.iter().enumerate() {
+                let (x1, y1) = points[(i + 1) % points.len()];
+                let (y0, y1, x0, x1) = (y0 as f64, y1 as f64, x0 as f64, x1 as f64);
+                if (y0 <= yc && yc < y1) || (y1 <= yc && yc < y0) {
+                    crossings.push(x0 + (yc - y0) * (x1 - x0) / (y1 - y0));
+                }
+            }
+            crossings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            for pair in crossings.chunks(2) {
+                if pair.len() == 2 {
+                    span(canvas, pair[0].ceil() as isize, pair[1].ceil() as isize - 1, y, fill);
+                }
+            }
+        }
+    }
+
+    if let Some(stroke) = style.stroke {
+        polyline(canvas, points, stroke, style.stroke_width);
+        line(canvas, points[points.len() - 1], points[0], stroke, style.stroke_width);
+    }
+}
+
+/// A pixel value with an opposite, see [invert_line](fn.invert_line.html).
+pub trait Invert {
+    /// The opposite pixel value.
+    fn invert(self) -> Self;
+}
+
+impl Invert for bool {
+    fn invert(self) -> bool {
+        !self
+    }
+}
+
+impl Invert for Argb {
+    /// Inverts the color channels and keeps alpha.
+    fn invert(self) -> Argb {
+        Argb::new(self.a, 255 - self.r, 255 - self.g, 255 - self.b)
+    }
+}
+
+/// Fills the bar behind an SDK text line, to highlight it. The canvas should be the
+/// background of the line's lcd, and `rects` says where the SDK draws its lines, usually
+/// [LineRects::default](../text/struct.LineRects.html).
+///
+/// Panics:
+/// - If a monochrome line is larger than 3 or a color line larger than 7.
+///
+pub fn highlight_line<C: Canvas>(canvas: &mut C, rects: &LineRects, line: TextLine,
+    color: C::Pixel)
+{
+    let bar = rects.rect(line);
+    rect(canvas, bar.x as isize, bar.y as isize, bar.width, bar.height, &Style::fill(color));
+}
+
+/// Inverts the background behind an SDK text line, for an inverse bar that keeps the
+/// background visible. See [highlight_line](fn.highlight_line.html).
+///
+/// Panics:
+/// - If a monochrome line is larger than 3 or a color line larger than 7.
+///
+pub fn invert_line<C: Canvas>(canvas: &mut C, rects: &LineRects, line: TextLine)
+    where C::Pixel: Invert
+{
+    let bar = rects.rect(line).intersect(&canvas.clip_rect());
+    for y in bar.y..bar.y + bar.height {
+        for x in bar.x..bar.x + bar.width {
+            let pixel = canvas.pixel(x, y);
+            canvas.set_pixel(x, y, pixel.invert());
+        }
+    }
+}
+
+/// A rectangle with rounded corners, the shape behind rectangles and circles.
+#[derive(Clone, Copy)]
+struct RoundedBox {
+    x: isize,
+    width: usize,
+    height: usize,
+    radius: usize,
+}
+
+impl RoundedBox {
+    fn new(x: isize, width: usize, height: usize, radius: usize) -> RoundedBox {
+        RoundedBox { x, width, height, radius: radius.min(width.min(height) / 2) }
+    }
+
+    /// Inclusive horizontal extent of a row, counted from the top of the box.
+    fn span(&self, row: usize) -> (isize, isize) {
+        let r = self.radius;
+        let dy = if row < r {
+            r - row
+        } else if row + r >= self.height {
+            row + r + 1 - self.height
+        } else {
+            0
+        };
+        let inset = if dy == 0 { 0 } else { r - half_width(r, dy) } as isize;
+        (self.x + inset, self.x + self.width as isize - 1 - inset)
+    }
+}
+
+/// The stroke width, 0 if there is no stroke.
+fn stroke_width<P>(style: &Style<P>) -> usize {
+    if style.stroke.is_some() { style.stroke_width } else { 0 }
+}
+
+/// Largest dx with dx² + dy² <= r² + r, the half width of a midpoint circle's row.
+fn half_width(r: usize, dy: usize) -> usize {
+    let n = r * r + r - dy * dy;
+    let mut x = (n as f64).sqrt() as usize;
+    while x * x > n {
+        x -= 1;
+    }
+    while (x + 1) * (x + 1) <= n {
+        x += 1;
+    }
+    x
+}
+
+/// Returns `true` if the pixel lies outside the filled circle of radius `inner`, if any.
+fn is_ring_edge(inner: Option<usize>, dx: isize, dy: isize) -> bool {
+    match inner {
+        Some(inner) => {
+            let (dx, dy) = (dx.unsigned_abs(), dy.unsigned_abs());
+            dy > inner || dx > half_width(inner, dy)
+        },
+        None => true,
+    }
+}
+
+fn in_sweep(dx: isize, dy: isize, start: f32, sweep: f32) -> bool {
+    if sweep >= 360.0 || (dx == 0 && dy == 0) {
+        return true;
+    }
+    let angle = (dy as f32).atan2(dx as f32).to_degrees();
+    (angle - start).rem_euclid(360.0) <= sweep
+}
+
+fn row_visible<C: Canvas>(canvas: &C, y: isize) -> bool {
+    let clip = canvas.clip_rect();
+    y >= clip.y as isize && y < (clip.y + clip.height) as isize
+}
+
+/// Fills the pixels from x0 to x1 inclusive on row y, clipped.
+fn span<C: Canvas>(canvas: &mut C, x0: isize, x1: isize, y: isize, color: C::Pixel) {
+    let clip = canvas.clip_rect();
+    if !row_visible(canvas, y) {
+        return;
+    }
+    let x0 = x0.max(clip.x as isize);
+    let x1 = x1.min((clip.x + clip.width) as isize - 1);
+    for x in x0..=x1 {
+        canvas.set_pixel(x as usize, y as usize, color);
+    }
+}
+
+/// Clips a line to the clip rect grown by the brush width, with Liang-Barsky.
+///
+/// Lines that are already inside are returned unchanged so they keep their exact pixels.
+fn clip_line<C: Canvas>(canvas: &C, from: (isize, isize), to: (isize, isize), width: isize)
+    -> Option<((isize, isize), (isize, isize))>
+{
+    let clip = canvas.clip_rect();
+    let left = clip.x as isize - width;
+    let top = clip.y as isize - width;
+    let right = (clip.x + clip.width) as isize + width;
+    let bottom = (clip.y + clip.height) as isize + width;
+    let inside = |p: (isize, isize)| p.0 >= left && p.0 <= right && p.1 >= top && p.1 <= bottom;
+    if inside(from) && inside(to) {
+        return Some((from, to));
+    }
+
+    let (x0, y0) = (from.0 as f64, from.1 as f64);
+    let (dx, dy) = ((to.0 - from.0) as f64, (to.1 - from.1) as f64);
+    let (mut t0, mut t1) = (0.0f64, 1.0f64);
+    let edges = [
+        (-dx, x0 - left as f64),
+        (dx, right as f64 - x0),
+        (-dy, y0 - top as f64),
+        (dy, bottom as f64 - y0),
+    ];
+    for &(p, q) in edges.iter() {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+        }
+    }
+    if t0 > t1 {
+        return None;
+    }
+
+    let at = |t: f64| ((x0 + t * dx).round() as isize, (y0 + t * dy).round() as isize);
+    Some((at(t0), at(t1)))
+}
@@ -0,0 +1,89 @@
+//! [embedded-graphics](https://docs.rs/embedded-graphics) support.
+//!
+//! Requires the `embedded-graphics` cargo feature.
+//!
+//! Implements `DrawTarget` for the monochrome buffers with `BinaryColor`, and for the color
+//! buffers with `Rgb888`, so widgets, fonts and image decoders from the embedded-graphics
+//! ecosystem draw directly onto LCD backgrounds. Drawing is clipped to the screen. Draw
+//! onto a [MonoFramebuffer](../framebuffer/struct.MonoFramebuffer.html) or
+//! [ColorFramebuffer](../framebuffer/struct.ColorFramebuffer.html) and `flush` it into the
+//! [Driver](../struct.Driver.html).
+//!
+//! ```no_run
+//! extern crate embedded_graphics;
+//! extern crate logitech_lcd;
+//!
+//! use embedded_graphics::mono_font::{ascii::FONT_6X10, MonoTextStyle};
+//! use embedded_graphics::pixelcolor::BinaryColor;
+//! use embedded_graphics::prelude::*;
+//! use embedded_graphics::primitives::{Circle, PrimitiveStyle};
+//! use embedded_graphics::text::Text;
+//! use logitech_lcd::framebuffer::MonoFramebuffer;
+//!
+//! # fn main() {
+//! let mut frame = MonoFramebuffer::new();
+//! Circle::new(Point::new(2, 2), 38)
+//!     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+//!     .draw(&mut frame).unwrap();
+//! Text::new("embedded-graphics", Point::new(48, 24),
+//!     MonoTextStyle::new(&FONT_6X10, BinaryColor::On))
+//!     .draw(&mut frame).unwrap();
+//!
+//! let mut driver = logitech_lcd::Driver::init_mono("embedded-graphics").unwrap();
+//! frame.flush(&mut driver).unwrap();
+//! driver.update();
+//! # }
+//! ```
+
+extern crate embedded_graphics_core;
+
+use self::embedded_graphics_core::draw_target::DrawTarget;
+use self::embedded_graphics_core::geometry::{OriginDimensions, Size};
+use self::embedded_graphics_core::pixelcolor::{BinaryColor, Rgb888, RgbColor};
+use self::embedded_graphics_core::Pixel;
+
+use std::convert::Infallible;
+
+use canvas::{Canvas, ColorCanvas, MonoCanvas};
+use color::Argb;
+use framebuffer::{ColorFramebuffer, MonoFramebuffer};
+use packed::PackedMonoBitmap;
+
+fn from_rgb888(color: Rgb888) -> Argb {
+    Argb::opaque(color.r(), color.g(), color.b())
+}
+
+macro_rules! impl_draw_target {
+    ($target:ty, $color:ty, $convert:expr) => {
+        impl OriginDimensions for $target {
+            fn size(&self) -> Size {
+                Size::new(Canvas::width(self) as u32, Canvas::height(self) as u32)
+            }
+        }
+
+        impl DrawTarget for $target {
+            type Color = $color;
+            type Error = Infallible;
+
+            fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Infallible>
+                where I: IntoIterator<Item = Pixel<$color>>
+            {
+                for Pixel(point, color) in pixels {
+                    self.put_pixel(point.x as isize, point.y as isize, $convert(color));
+                }
+                Ok(())
+            }
+
+            fn clear(&mut self, color: $color) -> Result<(), Infallible> {
+                Canvas::clear(self, $convert(color));
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_draw_target!(MonoCanvas<'_>, BinaryColor, BinaryColor::is_on);
+impl_draw_target!(PackedMonoBitmap, BinaryColor, BinaryColor::is_on);
+impl_draw_target!(MonoFramebuffer, BinaryColor, BinaryColor::is_on);
+impl_draw_target!(ColorCanvas<'_>, Rgb888, from_rgb888);
+impl_draw_target!(ColorFramebuffer, Rgb888, from_rgb888);
@@ -0,0 +1,397 @@
+//! Glyph data for the bundled fonts.
+//!
+//! Generated by `tools/fonts.py` from the public domain X11 misc-fixed fonts. Each glyph is
+//! one byte per row, top to bottom, with the leftmost pixel in the most significant bit.
+//! Glyphs cover the printable ASCII range, from `' '` to `'~'`.
+
+pub const GLYPHS_4X6: [u8; 570] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // ' '
+    0x40, 0x40, 0x40, 0x00, 0x40, 0x00, // '!'
+    0xa0, 0xa0, 0x00, 0x00, 0x00, 0x00, // '"'
+    0xa0, 0xf0, 0xa0, 0xf0, 0xa0, 0x00, // '#'
+    0x40, 0xe0, 0xc0, 0x20, 0xe0, 0x40, // '$'
+    0x80, 0x20, 0x40, 0x80, 0x20, 0x00, // '%'
+    0x40, 0xa0, 0x40, 0xa0, 0x50, 0x00, // '&'
+    0x40, 0x40, 0x00, 0x00, 0x00, 0x00, // '\''
+    0x20, 0x40, 0x40, 0x40, 0x40, 0x20, // '('
+    0x80, 0x40, 0x40, 0x40, 0x40, 0x80, // ')'
+    0xa0, 0x40, 0xe0, 0x40, 0xa0, 0x00, // '*'
+    0x40, 0x40, 0xe0, 0x40, 0x40, 0x00, // '+'
+    0x00, 0x00, 0x00, 0x00, 0x40, 0x80, // ','
+    0x00, 0x00, 0xe0, 0x00, 0x00, 0x00, // '-'
+    0x00, 0x00, 0x00, 0x00, 0x40, 0x00, // '.'
+    0x20, 0x20, 0x40, 0x80, 0x80, 0x00, // '/'
+    0x40, 0xa0, 0xe0, 0xa0, 0x40, 0x00, // '0'
+    0x40, 0xc0, 0x40, 0x40, 0xe0, 0x00, // '1'
+    0x40, 0xa0, 0x20, 0x40, 0xe0, 0x00, // '2'
+    0xe0, 0x20, 0x40, 0x20, 0xc0, 0x00, // '3'
+    0xa0, 0xa0, 0xe0, 0x20, 0x20, 0x00, // '4'
+    0xe0, 0x80, 0xc0, 0x20, 0xc0, 0x00, // '5'
+    0x60, 0x80, 0xc0, 0xa0, 0x40, 0x00, // '6'
+    0xe0, 0x20, 0x40, 0x80, 0x80, 0x00, // '7'
+    0x60, 0xa0, 0x40, 0xa0, 0xc0, 0x00, // '8'
+    0x40, 0xa0, 0x60, 0x20, 0xc0, 0x00, // '9'
+    0x00, 0x40, 0x00, 0x00, 0x40, 0x00, // ':'
+    0x00, 0x40, 0x00, 0x00, 0x40, 0x80, // ';'
+    0x20, 0x40, 0x80, 0x40, 0x20, 0x00, // '<'
+    0x00, 0xe0, 0x00, 0xe0, 0x00, 0x00, // '='
+    0x80, 0x40, 0x20, 0x40, 0x80, 0x00, // '>'
+    0xc0, 0x20, 0x40, 0x00, 0x40, 0x00, // '?'
+    0x60, 0xa0, 0xa0, 0x80, 0x60, 0x00, // '@'
+    0x40, 0xa0, 0xe0, 0xa0, 0xa0, 0x00, // 'A'
+    0xc0, 0xa0, 0xc0, 0xa0, 0xc0, 0x00, // 'B'
+    0x40, 0xa0, 0x80, 0xa0, 0x40, 0x00, // 'C'
+    0xc0, 0xa0, 0xa0, 0xa0, 0xc0, 0x00, // 'D'
+    0xe0, 0x80, 0xc0, 0x80, 0xe0, 0x00, // 'E'
+    0xe0, 0x80, 0xc0, 0x80, 0x80, 0x00, // 'F'
+    0x60, 0x80, 0xa0, 0xa0, 0x60, 0x00, // 'G'
+    0xa0, 0xa0, 0xe0, 0xa0, 0xa0, 0x00, // 'H'
+    0xe0, 0x40, 0x40, 0x40, 0xe0, 0x00, // 'I'
+    0x20, 0x20, 0x20, 0xa0, 0x40, 0x00, // 'J'
+    0xa0, 0xa0, 0xc0, 0xa0, 0xa0, 0x00, // 'K'
+    0x80, 0x80, 0x80, 0x80, 0xe0, 0x00, // 'L'
+    0xa0, 0xe0, 0xe0, 0xa0, 0xa0, 0x00, // 'M'
+    0x20, 0xa0, 0xe0, 0xa0, 0x80, 0x00, // 'N'
+    0x40, 0xa0, 0xa0, 0xa0, 0x40, 0x00, // 'O'
+    0xc0, 0xa0, 0xc0, 0x80, 0x80, 0x00, // 'P'
+    0x40, 0xa0, 0xa0, 0xa0, 0x40, 0x20, // 'Q'
+    0xc0, 0xa0, 0xc0, 0xa0, 0xa0, 0x00, // 'R'
+    0x60, 0x80, 0x40, 0x20, 0xc0, 0x00, // 'S'
+    0xe0, 0x40, 0x40, 0x40, 0x40, 0x00, // 'T'
+    0xa0, 0xa0, 0xa0, 0xa0, 0xe0, 0x00, // 'U'
+    0xa0, 0xa0, 0xa0, 0xe0, 0x40, 0x00, // 'V'
+    0xa0, 0xa0, 0xe0, 0xe0, 0xa0, 0x00, // 'W'
+    0xa0, 0xa0, 0x40, 0xa0, 0xa0, 0x00, // 'X'
+    0xa0, 0xa0, 0x40, 0x40, 0x40, 0x00, // 'Y'
+    0xe0, 0x20, 0x40, 0x80, 0xe0, 0x00, // 'Z'
+    0x60, 0x40, 0x40, 0x40, 0x60, 0x00, // '['
+    0x80, 0x80, 0x40, 0x20, 0x20, 0x00, // '\\'
+    0xc0, 0x40, 0x40, 0x40, 0xc0, 0x00, // ']'
+    0x40, 0xa0, 0x00, 0x00, 0x00, 0x00, // '^'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0xe0, // '_'
+    0x40, 0x20, 0x00, 0x00, 0x00, 0x00, // '`'
+    0x00, 0x60, 0xa0, 0xa0, 0x60, 0x00, // 'a'
+    0x80, 0xc0, 0xa0, 0xa0, 0xc0, 0x00, // 'b'
+    0x00, 0x60, 0x80, 0x80, 0x60, 0x00, // 'c'
+    0x20, 0x60, 0xa0, 0xa0, 0x60, 0x00, // 'd'
+    0x00, 0x40, 0xa0, 0xc0, 0x60, 0x00, // 'e'
+    0x20, 0x40, 0xe0, 0x40, 0x40, 0x00, // 'f'
+    0x00, 0x60, 0xa0, 0x60, 0x20, 0xc0, // 'g'
+    0x80, 0xc0, 0xa0, 0xa0, 0xa0, 0x00, // 'h'
+    0x40, 0x00, 0xc0, 0x40, 0xe0, 0x00, // 'i'
+    0x20, 0x00, 0x20, 0x20, 0x20, 0xc0, // 'j'
+    0x80, 0xa0, 0xc0, 0xa0, 0xa0, 0x00, // 'k'
+    0xc0, 0x40, 0x40, 0x40, 0xe0, 0x00, // 'l'
+    0x00, 0xa0, 0xe0, 0xa0, 0xa0, 0x00, // 'm'
+    0x00, 0xc0, 0xa0, 0xa0, 0xa0, 0x00, // 'n'
+    0x00, 0x40, 0xa0, 0xa0, 0x40, 0x00, // 'o'
+    0x00, 0xc0, 0xa0, 0xc0, 0x80, 0x80, // 'p'
+    0x00, 0x60, 0xa0, 0xa0, 0x60, 0x20, // 'q'
+    0x00, 0xa0, 0xc0, 0x80, 0x80, 0x00, // 'r'
+    0x00, 0x60, 0xc0, 0x20, 0xc0, 0x00, // 's'
+    0x40, 0xe0, 0x40, 0x40, 0x20, 0x00, // 't'
+    0x00, 0xa0, 0xa0, 0xa0, 0x60, 0x00, // 'u'
+    0x00, 0xa0, 0xa0, 0xa0, 0x40, 0x00, // 'v'
+    0x00, 0xa0, 0xa0, 0xe0, 0xa0, 0x00, // 'w'
+    0x00, 0xa0, 0x40, 0x40, 0xa0, 0x00, // 'x'
+    0x00, 0xa0, 0xa0, 0x60, 0x20, 0xc0, // 'y'
+    0x00, 0xe0, 0x20, 0x40, 0xe0, 0x00, // 'z'
+    0x20, 0x40, 0xc0, 0x40, 0x40, 0x20, // '{'
+    0x40, 0x40, 0x40, 0x40, 0x40, 0x00, // '|'
+    0x80, 0x40, 0x60, 0x40, 0x40, 0x80, // '}'
+    0x50, 0xa0, 0x00, 0x00, 0x00, 0x00, // '~'
+];
+
+pub const GLYPHS_5X7: [u8; 665] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // ' '
+    0x20, 0x20, 0x20, 0x20, 0x00, 0x20, 0x00, // '!'
+    0x50, 0x50, 0x50, 0x00, 0x00, 0x00, 0x00, // '"'
+    0x00, 0x50, 0xf8, 0x50, 0xf8, 0x50, 0x00, // '#'
+    0x00, 0x70, 0xa0, 0x70, 0x28, 0x70, 0x00, // '$'
+    0x80, 0x90, 0x20, 0x40, 0x90, 0x10, 0x00, // '%'
+    0x00, 0x40, 0xa0, 0x40, 0xa0, 0x50, 0x00, // '&'
+    0x20, 0x20, 0x20, 0x00, 0x00, 0x00, 0x00, // '\''
+    0x20, 0x40, 0x40, 0x40, 0x40, 0x20, 0x00, // '('
+    0x40, 0x20, 0x20, 0x20, 0x20, 0x40, 0x00, // ')'
+    0x00, 0x50, 0x20, 0x70, 0x20, 0x50, 0x00, // '*'
+    0x00, 0x20, 0x20, 0xf8, 0x20, 0x20, 0x00, // '+'
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x20, 0x40, // ','
+    0x00, 0x00, 0x00, 0xf0, 0x00, 0x00, 0x00, // '-'
+    0x00, 0x00, 0x00, 0x00, 0x60, 0x60, 0x00, // '.'
+    0x00, 0x10, 0x20, 0x40, 0x80, 0x00, 0x00, // '/'
+    0x20, 0x50, 0x50, 0x50, 0x50, 0x20, 0x00, // '0'
+    0x20, 0x60, 0x20, 0x20, 0x20, 0x70, 0x00, // '1'
+    0x60, 0x90, 0x10, 0x20, 0x40, 0xf0, 0x00, // '2'
+    0xf0, 0x10, 0x60, 0x10, 0x90, 0x60, 0x00, // '3'
+    0x20, 0x60, 0xa0, 0xf0, 0x20, 0x20, 0x00, // '4'
+    0xf0, 0x80, 0xe0, 0x10, 0x90, 0x60, 0x00, // '5'
+    0x60, 0x80, 0xe0, 0x90, 0x90, 0x60, 0x00, // '6'
+    0xf0, 0x10, 0x20, 0x20, 0x40, 0x40, 0x00, // '7'
+    0x60, 0x90, 0x60, 0x90, 0x90, 0x60, 0x00, // '8'
+    0x60, 0x90, 0x90, 0x70, 0x10, 0x60, 0x00, // '9'
+    0x00, 0x60, 0x60, 0x00, 0x60, 0x60, 0x00, // ':'
+    0x00, 0x60, 0x60, 0x00, 0x60, 0x40, 0x80, // ';'
+    0x00, 0x10, 0x20, 0x40, 0x20, 0x10, 0x00, // '<'
+    0x00, 0x00, 0xf0, 0x00, 0xf0, 0x00, 0x00, // '='
+    0x00, 0x40, 0x20, 0x10, 0x20, 0x40, 0x00, // '>'
+    0x20, 0x50, 0x10, 0x20, 0x00, 0x20, 0x00, // '?'
+    0x60, 0x90, 0xb0, 0xb0, 0x80, 0x60, 0x00, // '@'
+    0x60, 0x90, 0x90, 0xf0, 0x90, 0x90, 0x00, // 'A'
+    0xe0, 0x90, 0xe0, 0x90, 0x90, 0xe0, 0x00, // 'B'
+    0x60, 0x90, 0x80, 0x80, 0x90, 0x60, 0x00, // 'C'
+    0xe0, 0x90, 0x90, 0x90, 0x90, 0xe0, 0x00, // 'D'
+    0xf0, 0x80, 0xe0, 0x80, 0x80, 0xf0, 0x00, // 'E'
+    0xf0, 0x80, 0xe0, 0x80, 0x80, 0x80, 0x00, // 'F'
+    0x60, 0x90, 0x80, 0xb0, 0x90, 0x70, 0x00, // 'G'
+    0x90, 0x90, 0xf0, 0x90, 0x90, 0x90, 0x00, // 'H'
+    0x70, 0x20, 0x20, 0x20, 0x20, 0x70, 0x00, // 'I'
+    0x10, 0x10, 0x10, 0x10, 0x90, 0x60, 0x00, // 'J'
+    0x90, 0xa0, 0xc0, 0xc0, 0xa0, 0x90, 0x00, // 'K'
+    0x80, 0x80, 0x80, 0x80, 0x80, 0xf0, 0x00, // 'L'
+    0x90, 0xf0, 0xf0, 0x90, 0x90, 0x90, 0x00, // 'M'
+    0x90, 0xd0, 0xd0, 0xb0, 0xb0, 0x90, 0x00, // 'N'
+    0x60, 0x90, 0x90, 0x90, 0x90, 0x60, 0x00, // 'O'
+    0xe0, 0x90, 0x90, 0xe0, 0x80, 0x80, 0x00, // 'P'
+    0x60, 0x90, 0x90, 0x90, 0xd0, 0x60, 0x10, // 'Q'
+    0xe0, 0x90, 0x90, 0xe0, 0xa0, 0x90, 0x00, // 'R'
+    0x60, 0x90, 0x40, 0x20, 0x90, 0x60, 0x00, // 'S'
+    0x70, 0x20, 0x20, 0x20, 0x20, 0x20, 0x00, // 'T'
+    0x90, 0x90, 0x90, 0x90, 0x90, 0x60, 0x00, // 'U'
+    0x90, 0x90, 0x90, 0x90, 0x60, 0x60, 0x00, // 'V'
+    0x90, 0x90, 0x90, 0xf0, 0xf0, 0x90, 0x00, // 'W'
+    0x90, 0x90, 0x60, 0x60, 0x90, 0x90, 0x00, // 'X'
+    0x50, 0x50, 0x50, 0x20, 0x20, 0x20, 0x00, // 'Y'
+    0xf0, 0x10, 0x20, 0x40, 0x80, 0xf0, 0x00, // 'Z'
+    0x70, 0x40, 0x40, 0x40, 0x40, 0x70, 0x00, // '['
+    0x00, 0x80, 0x40, 0x20, 0x10, 0x00, 0x00, // '\\'
+    0x70, 0x10, 0x10, 0x10, 0x10, 0x70, 0x00, // ']'
+    0x20, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, // '^'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x00, // '_'
+    0x40, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, // '`'
+    0x00, 0x00, 0x70, 0x90, 0xb0, 0x50, 0x00, // 'a'
+    0x80, 0x80, 0xe0, 0x90, 0x90, 0xe0, 0x00, // 'b'
+    0x00, 0x00, 0x60, 0x80, 0x80, 0x60, 0x00, // 'c'
+    0x10, 0x10, 0x70, 0x90, 0x90, 0x70, 0x00, // 'd'
+    0x00, 0x00, 0x60, 0xb0, 0xc0, 0x60, 0x00, // 'e'
+    0x20, 0x50, 0x40, 0xe0, 0x40, 0x40, 0x00, // 'f'
+    0x00, 0x00, 0x70, 0x90, 0x60, 0x80, 0x70, // 'g'
+    0x80, 0x80, 0xe0, 0x90, 0x90, 0x90, 0x00, // 'h'
+    0x20, 0x00, 0x60, 0x20, 0x20, 0x70, 0x00, // 'i'
+    0x10, 0x00, 0x10, 0x10, 0x10, 0x50, 0x20, // 'j'
+    0x80, 0x80, 0xa0, 0xc0, 0xa0, 0x90, 0x00, // 'k'
+    0x60, 0x20, 0x20, 0x20, 0x20, 0x70, 0x00, // 'l'
+    0x00, 0x00, 0xa0, 0xf0, 0x90, 0x90, 0x00, // 'm'
+    0x00, 0x00, 0xe0, 0x90, 0x90, 0x90, 0x00, // 'n'
+    0x00, 0x00, 0x60, 0x90, 0x90, 0x60, 0x00, // 'o'
+    0x00, 0x00, 0xe0, 0x90, 0x90, 0xe0, 0x80, // 'p'
+    0x00, 0x00, 0x70, 0x90, 0x90, 0x70, 0x10, // 'q'
+    0x00, 0x00, 0xe0, 0x90, 0x80, 0x80, 0x00, // 'r'
+    0x00, 0x00, 0x70, 0xc0, 0x30, 0xe0, 0x00, // 's'
+    0x40, 0x40, 0xe0, 0x40, 0x40, 0x30, 0x00, // 't'
+    0x00, 0x00, 0x90, 0x90, 0x90, 0x70, 0x00, // 'u'
+    0x00, 0x00, 0x50, 0x50, 0x50, 0x20, 0x00, // 'v'
+    0x00, 0x00, 0x90, 0x90, 0xf0, 0xf0, 0x00, // 'w'
+    0x00, 0x00, 0x90, 0x60, 0x60, 0x90, 0x00, // 'x'
+    0x00, 0x00, 0x90, 0x90, 0x50, 0x20, 0x40, // 'y'
+    0x00, 0x00, 0xf0, 0x20, 0x40, 0xf0, 0x00, // 'z'
+    0x10, 0x20, 0x60, 0x20, 0x20, 0x10, 0x00, // '{'
+    0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x00, // '|'
+    0x40, 0x20, 0x30, 0x20, 0x20, 0x40, 0x00, // '}'
+    0x50, 0xa0, 0x00, 0x00, 0x00, 0x00, 0x00, // '~'
+];
+
+pub const GLYPHS_6X10: [u8; 950] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // ' '
+    0x00, 0x20, 0x20, 0x20, 0x20, 0x20, 0x00, 0x20, 0x00, 0x00, // '!'
+    0x00, 0x50, 0x50, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '"'
+    0x00, 0x50, 0x50, 0xf8, 0x50, 0xf8, 0x50, 0x50, 0x00, 0x00, // '#'
+    0x00, 0x20, 0x70, 0xa0, 0x70, 0x28, 0x70, 0x20, 0x00, 0x00, // '$'
+    0x00, 0x48, 0xa8, 0x50, 0x20, 0x50, 0xa8, 0x90, 0x00, 0x00, // '%'
+    0x00, 0x40, 0xa0, 0xa0, 0x40, 0xa8, 0x90, 0x68, 0x00, 0x00, // '&'
+    0x00, 0x20, 0x20, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '\''
+    0x00, 0x10, 0x20, 0x40, 0x40, 0x40, 0x20, 0x10, 0x00, 0x00, // '('
+    0x00, 0x40, 0x20, 0x10, 0x10, 0x10, 0x20, 0x40, 0x00, 0x00, // ')'
+    0x00, 0x00, 0x88, 0x50, 0xf8, 0x50, 0x88, 0x00, 0x00, 0x00, // '*'
+    0x00, 0x00, 0x20, 0x20, 0xf8, 0x20, 0x20, 0x00, 0x00, 0x00, // '+'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x20, 0x40, 0x00, // ','
+    0x00, 0x00, 0x00, 0x00, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, // '-'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x70, 0x20, 0x00, // '.'
+    0x00, 0x08, 0x08, 0x10, 0x20, 0x40, 0x80, 0x80, 0x00, 0x00, // '/'
+    0x00, 0x20, 0x50, 0x88, 0x88, 0x88, 0x50, 0x20, 0x00, 0x00, // '0'
+    0x00, 0x20, 0x60, 0xa0, 0x20, 0x20, 0x20, 0xf8, 0x00, 0x00, // '1'
+    0x00, 0x70, 0x88, 0x08, 0x30, 0x40, 0x80, 0xf8, 0x00, 0x00, // '2'
+    0x00, 0xf8, 0x08, 0x10, 0x30, 0x08, 0x88, 0x70, 0x00, 0x00, // '3'
+    0x00, 0x10, 0x30, 0x50, 0x90, 0xf8, 0x10, 0x10, 0x00, 0x00, // '4'
+    0x00, 0xf8, 0x80, 0xb0, 0xc8, 0x08, 0x88, 0x70, 0x00, 0x00, // '5'
+    0x00, 0x30, 0x40, 0x80, 0xb0, 0xc8, 0x88, 0x70, 0x00, 0x00, // '6'
+    0x00, 0xf8, 0x08, 0x10, 0x10, 0x20, 0x40, 0x40, 0x00, 0x00, // '7'
+    0x00, 0x70, 0x88, 0x88, 0x70, 0x88, 0x88, 0x70, 0x00, 0x00, // '8'
+    0x00, 0x70, 0x88, 0x98, 0x68, 0x08, 0x10, 0x60, 0x00, 0x00, // '9'
+    0x00, 0x00, 0x20, 0x70, 0x20, 0x00, 0x20, 0x70, 0x20, 0x00, // ':'
+    0x00, 0x00, 0x20, 0x70, 0x20, 0x00, 0x30, 0x20, 0x40, 0x00, // ';'
+    0x00, 0x08, 0x10, 0x20, 0x40, 0x20, 0x10, 0x08, 0x00, 0x00, // '<'
+    0x00, 0x00, 0x00, 0xf8, 0x00, 0xf8, 0x00, 0x00, 0x00, 0x00, // '='
+    0x00, 0x40, 0x20, 0x10, 0x08, 0x10, 0x20, 0x40, 0x00, 0x00, // '>'
+    0x00, 0x70, 0x88, 0x10, 0x20, 0x20, 0x00, 0x20, 0x00, 0x00, // '?'
+    0x00, 0x70, 0x88, 0x98, 0xa8, 0xb0, 0x80, 0x70, 0x00, 0x00, // '@'
+    0x00, 0x20, 0x50, 0x88, 0x88, 0xf8, 0x88, 0x88, 0x00, 0x00, // 'A'
+    0x00, 0xf0, 0x48, 0x48, 0x70, 0x48, 0x48, 0xf0, 0x00, 0x00, // 'B'
+    0x00, 0x70, 0x88, 0x80, 0x80, 0x80, 0x88, 0x70, 0x00, 0x00, // 'C'
+    0x00, 0xf0, 0x48, 0x48, 0x48, 0x48, 0x48, 0xf0, 0x00, 0x00, // 'D'
+    0x00, 0xf8, 0x80, 0x80, 0xf0, 0x80, 0x80, 0xf8, 0x00, 0x00, // 'E'
+    0x00, 0xf8, 0x80, 0x80, 0xf0, 0x80, 0x80, 0x80, 0x00, 0x00, // 'F'
+    0x00, 0x70, 0x88, 0x80, 0x80, 0x98, 0x88, 0x70, 0x00, 0x00, // 'G'
+    0x00, 0x88, 0x88, 0x88, 0xf8, 0x88, 0x88, 0x88, 0x00, 0x00, // 'H'
+    0x00, 0x70, 0x20, 0x20, 0x20, 0x20, 0x20, 0x70, 0x00, 0x00, // 'I'
+    0x00, 0x38, 0x10, 0x10, 0x10, 0x10, 0x90, 0x60, 0x00, 0x00, // 'J'
+    0x00, 0x88, 0x90, 0xa0, 0xc0, 0xa0, 0x90, 0x88, 0x00, 0x00, // 'K'
+    0x00, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0xf8, 0x00, 0x00, // 'L'
+    0x00, 0x88, 0x88, 0xd8, 0xa8, 0x88, 0x88, 0x88, 0x00, 0x00, // 'M'
+    0x00, 0x88, 0x88, 0xc8, 0xa8, 0x98, 0x88, 0x88, 0x00, 0x00, // 'N'
+    0x00, 0x70, 0x88, 0x88, 0x88, 0x88, 0x88, 0x70, 0x00, 0x00, // 'O'
+    0x00, 0xf0, 0x88, 0x88, 0xf0, 0x80, 0x80, 0x80, 0x00, 0x00, // 'P'
+    0x00, 0x70, 0x88, 0x88, 0x88, 0x88, 0xa8, 0x70, 0x08, 0x00, // 'Q'
+    0x00, 0xf0, 0x88, 0x88, 0xf0, 0xa0, 0x90, 0x88, 0x00, 0x00, // 'R'
+    0x00, 0x70, 0x88, 0x80, 0x70, 0x08, 0x88, 0x70, 0x00, 0x00, // 'S'
+    0x00, 0xf8, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x00, 0x00, // 'T'
+    0x00, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x70, 0x00, 0x00, // 'U'
+    0x00, 0x88, 0x88, 0x88, 0x50, 0x50, 0x50, 0x20, 0x00, 0x00, // 'V'
+    0x00, 0x88, 0x88, 0x88, 0xa8, 0xa8, 0xd8, 0x88, 0x00, 0x00, // 'W'
+    0x00, 0x88, 0x88, 0x50, 0x20, 0x50, 0x88, 0x88, 0x00, 0x00, // 'X'
+    0x00, 0x88, 0x88, 0x50, 0x20, 0x20, 0x20, 0x20, 0x00, 0x00, // 'Y'
+    0x00, 0xf8, 0x08, 0x10, 0x20, 0x40, 0x80, 0xf8, 0x00, 0x00, // 'Z'
+    0x00, 0x70, 0x40, 0x40, 0x40, 0x40, 0x40, 0x70, 0x00, 0x00, // '['
+    0x00, 0x80, 0x80, 0x40, 0x20, 0x10, 0x08, 0x08, 0x00, 0x00, // '\\'
+    0x00, 0x70, 0x10, 0x10, 0x10, 0x10, 0x10, 0x70, 0x00, 0x00, // ']'
+    0x00, 0x20, 0x50, 0x88, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '^'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf8, 0x00, // '_'
+    0x20, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '`'
+    0x00, 0x00, 0x00, 0x70, 0x08, 0x78, 0x88, 0x78, 0x00, 0x00, // 'a'
+    0x00, 0x80, 0x80, 0xb0, 0xc8, 0x88, 0xc8, 0xb0, 0x00, 0x00, // 'b'
+    0x00, 0x00, 0x00, 0x70, 0x88, 0x80, 0x88, 0x70, 0x00, 0x00, // 'c'
+    0x00, 0x08, 0x08, 0x68, 0x98, 0x88, 0x98, 0x68, 0x00, 0x00, // 'd'
+    0x00, 0x00, 0x00, 0x70, 0x88, 0xf8, 0x80, 0x70, 0x00, 0x00, // 'e'
+    0x00, 0x30, 0x48, 0x40, 0xf0, 0x40, 0x40, 0x40, 0x00, 0x00, // 'f'
+    0x00, 0x00, 0x00, 0x78, 0x88, 0x88, 0x78, 0x08, 0x88, 0x70, // 'g'
+    0x00, 0x80, 0x80, 0xb0, 0xc8, 0x88, 0x88, 0x88, 0x00, 0x00, // 'h'
+    0x00, 0x20, 0x00, 0x60, 0x20, 0x20, 0x20, 0x70, 0x00, 0x00, // 'i'
+    0x00, 0x08, 0x00, 0x18, 0x08, 0x08, 0x08, 0x48, 0x48, 0x30, // 'j'
+    0x00, 0x80, 0x80, 0x88, 0x90, 0xe0, 0x90, 0x88, 0x00, 0x00, // 'k'
+    0x00, 0x60, 0x20, 0x20, 0x20, 0x20, 0x20, 0x70, 0x00, 0x00, // 'l'
+    0x00, 0x00, 0x00, 0xd0, 0xa8, 0xa8, 0xa8, 0x88, 0x00, 0x00, // 'm'
+    0x00, 0x00, 0x00, 0xb0, 0xc8, 0x88, 0x88, 0x88, 0x00, 0x00, // 'n'
+    0x00, 0x00, 0x00, 0x70, 0x88, 0x88, 0x88, 0x70, 0x00, 0x00, // 'o'
+    0x00, 0x00, 0x00, 0xb0, 0xc8, 0x88, 0xc8, 0xb0, 0x80, 0x80, // 'p'
+    0x00, 0x00, 0x00, 0x68, 0x98, 0x88, 0x98, 0x68, 0x08, 0x08, // 'q'
+    0x00, 0x00, 0x00, 0xb0, 0xc8, 0x80, 0x80, 0x80, 0x00, 0x00, // 'r'
+    0x00, 0x00, 0x00, 0x70, 0x80, 0x70, 0x08, 0xf0, 0x00, 0x00, // 's'
+    0x00, 0x40, 0x40, 0xf0, 0x40, 0x40, 0x48, 0x30, 0x00, 0x00, // 't'
+    0x00, 0x00, 0x00, 0x88, 0x88, 0x88, 0x98, 0x68, 0x00, 0x00, // 'u'
+    0x00, 0x00, 0x00, 0x88, 0x88, 0x50, 0x50, 0x20, 0x00, 0x00, // 'v'
+    0x00, 0x00, 0x00, 0x88, 0x88, 0xa8, 0xa8, 0x50, 0x00, 0x00, // 'w'
+    0x00, 0x00, 0x00, 0x88, 0x50, 0x20, 0x50, 0x88, 0x00, 0x00, // 'x'
+    0x00, 0x00, 0x00, 0x88, 0x88, 0x98, 0x68, 0x08, 0x88, 0x70, // 'y'
+    0x00, 0x00, 0x00, 0xf8, 0x10, 0x20, 0x40, 0xf8, 0x00, 0x00, // 'z'
+    0x00, 0x18, 0x20, 0x10, 0x60, 0x10, 0x20, 0x18, 0x00, 0x00, // '{'
+    0x00, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x00, 0x00, // '|'
+    0x00, 0x60, 0x10, 0x20, 0x18, 0x20, 0x10, 0x60, 0x00, 0x00, // '}'
+    0x00, 0x48, 0xa8, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '~'
+];
+
+pub const GLYPHS_8X13: [u8; 1235] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // ' '
+    0x00, 0x00, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00, 0x10, 0x00, 0x00, // '!'
+    0x00, 0x00, 0x24, 0x24, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '"'
+    0x00, 0x00, 0x00, 0x24, 0x24, 0x7e, 0x24, 0x7e, 0x24, 0x24, 0x00, 0x00, 0x00, // '#'
+    0x00, 0x00, 0x10, 0x3c, 0x50, 0x50, 0x38, 0x14, 0x14, 0x78, 0x10, 0x00, 0x00, // '$'
+    0x00, 0x00, 0x22, 0x52, 0x24, 0x08, 0x08, 0x10, 0x24, 0x2a, 0x44, 0x00, 0x00, // '%'
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x48, 0x48, 0x30, 0x4a, 0x44, 0x3a, 0x00, 0x00, // '&'
+    0x00, 0x00, 0x10, 0x10, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '\''
+    0x00, 0x00, 0x04, 0x08, 0x08, 0x10, 0x10, 0x10, 0x08, 0x08, 0x04, 0x00, 0x00, // '('
+    0x00, 0x00, 0x20, 0x10, 0x10, 0x08, 0x08, 0x08, 0x10, 0x10, 0x20, 0x00, 0x00, // ')'
+    0x00, 0x00, 0x24, 0x18, 0x7e, 0x18, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '*'
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x10, 0x7c, 0x10, 0x10, 0x00, 0x00, 0x00, 0x00, // '+'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x38, 0x30, 0x40, 0x00, // ','
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '-'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x38, 0x10, 0x00, // '.'
+    0x00, 0x00, 0x02, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x80, 0x00, 0x00, // '/'
+    0x00, 0x00, 0x18, 0x24, 0x42, 0x42, 0x42, 0x42, 0x42, 0x24, 0x18, 0x00, 0x00, // '0'
+    0x00, 0x00, 0x10, 0x30, 0x50, 0x10, 0x10, 0x10, 0x10, 0x10, 0x7c, 0x00, 0x00, // '1'
+    0x00, 0x00, 0x3c, 0x42, 0x42, 0x02, 0x04, 0x18, 0x20, 0x40, 0x7e, 0x00, 0x00, // '2'
+    0x00, 0x00, 0x7e, 0x02, 0x04, 0x08, 0x1c, 0x02, 0x02, 0x42, 0x3c, 0x00, 0x00, // '3'
+    0x00, 0x00, 0x04, 0x0c, 0x14, 0x24, 0x44, 0x44, 0x7e, 0x04, 0x04, 0x00, 0x00, // '4'
+    0x00, 0x00, 0x7e, 0x40, 0x40, 0x5c, 0x62, 0x02, 0x02, 0x42, 0x3c, 0x00, 0x00, // '5'
+    0x00, 0x00, 0x1c, 0x20, 0x40, 0x40, 0x5c, 0x62, 0x42, 0x42, 0x3c, 0x00, 0x00, // '6'
+    0x00, 0x00, 0x7e, 0x02, 0x04, 0x08, 0x08, 0x10, 0x10, 0x20, 0x20, 0x00, 0x00, // '7'
+    0x00, 0x00, 0x3c, 0x42, 0x42, 0x42, 0x3c, 0x42, 0x42, 0x42, 0x3c, 0x00, 0x00, // '8'
+    0x00, 0x00, 0x3c, 0x42, 0x42, 0x46, 0x3a, 0x02, 0x02, 0x04, 0x38, 0x00, 0x00, // '9'
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x38, 0x10, 0x00, 0x00, 0x10, 0x38, 0x10, 0x00, // ':'
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x38, 0x10, 0x00, 0x00, 0x38, 0x30, 0x40, 0x00, // ';'
+    0x00, 0x00, 0x02, 0x04, 0x08, 0x10, 0x20, 0x10, 0x08, 0x04, 0x02, 0x00, 0x00, // '<'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x00, 0x00, // '='
+    0x00, 0x00, 0x40, 0x20, 0x10, 0x08, 0x04, 0x08, 0x10, 0x20, 0x40, 0x00, 0x00, // '>'
+    0x00, 0x00, 0x3c, 0x42, 0x42, 0x02, 0x04, 0x08, 0x08, 0x00, 0x08, 0x00, 0x00, // '?'
+    0x00, 0x00, 0x3c, 0x42, 0x42, 0x4e, 0x52, 0x56, 0x4a, 0x40, 0x3c, 0x00, 0x00, // '@'
+    0x00, 0x00, 0x18, 0x24, 0x42, 0x42, 0x42, 0x7e, 0x42, 0x42, 0x42, 0x00, 0x00, // 'A'
+    0x00, 0x00, 0x78, 0x44, 0x42, 0x44, 0x78, 0x44, 0x42, 0x44, 0x78, 0x00, 0x00, // 'B'
+    0x00, 0x00, 0x3c, 0x42, 0x40, 0x40, 0x40, 0x40, 0x40, 0x42, 0x3c, 0x00, 0x00, // 'C'
+    0x00, 0x00, 0x78, 0x44, 0x42, 0x42, 0x42, 0x42, 0x42, 0x44, 0x78, 0x00, 0x00, // 'D'
+    0x00, 0x00, 0x7e, 0x40, 0x40, 0x40, 0x78, 0x40, 0x40, 0x40, 0x7e, 0x00, 0x00, // 'E'
+    0x00, 0x00, 0x7e, 0x40, 0x40, 0x40, 0x78, 0x40, 0x40, 0x40, 0x40, 0x00, 0x00, // 'F'
+    0x00, 0x00, 0x3c, 0x42, 0x40, 0x40, 0x40, 0x4e, 0x42, 0x46, 0x3a, 0x00, 0x00, // 'G'
+    0x00, 0x00, 0x42, 0x42, 0x42, 0x42, 0x7e, 0x42, 0x42, 0x42, 0x42, 0x00, 0x00, // 'H'
+    0x00, 0x00, 0x7c, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x7c, 0x00, 0x00, // 'I'
+    0x00, 0x00, 0x1f, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x44, 0x38, 0x00, 0x00, // 'J'
+    0x00, 0x00, 0x42, 0x44, 0x48, 0x50, 0x60, 0x50, 0x48, 0x44, 0x42, 0x00, 0x00, // 'K'
+    0x00, 0x00, 0x40, 0x40, 0x40, 0x40, 0x40, 0x40, 0x40, 0x40, 0x7e, 0x00, 0x00, // 'L'
+    0x00, 0x00, 0x82, 0x82, 0xc6, 0xaa, 0x92, 0x92, 0x82, 0x82, 0x82, 0x00, 0x00, // 'M'
+    0x00, 0x00, 0x42, 0x42, 0x62, 0x52, 0x4a, 0x46, 0x42, 0x42, 0x42, 0x00, 0x00, // 'N'
+    0x00, 0x00, 0x3c, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x3c, 0x00, 0x00, // 'O'
+    0x00, 0x00, 0x7c, 0x42, 0x42, 0x42, 0x7c, 0x40, 0x40, 0x40, 0x40, 0x00, 0x00, // 'P'
+    0x00, 0x00, 0x3c, 0x42, 0x42, 0x42, 0x42, 0x42, 0x52, 0x4a, 0x3c, 0x02, 0x00, // 'Q'
+    0x00, 0x00, 0x7c, 0x42, 0x42, 0x42, 0x7c, 0x50, 0x48, 0x44, 0x42, 0x00, 0x00, // 'R'
+    0x00, 0x00, 0x3c, 0x42, 0x40, 0x40, 0x3c, 0x02, 0x02, 0x42, 0x3c, 0x00, 0x00, // 'S'
+    0x00, 0x00, 0xfe, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00, 0x00, // 'T'
+    0x00, 0x00, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x3c, 0x00, 0x00, // 'U'
+    0x00, 0x00, 0x82, 0x82, 0x44, 0x44, 0x44, 0x28, 0x28, 0x28, 0x10, 0x00, 0x00, // 'V'
+    0x00, 0x00, 0x82, 0x82, 0x82, 0x82, 0x92, 0x92, 0x92, 0xaa, 0x44, 0x00, 0x00, // 'W'
+    0x00, 0x00, 0x82, 0x82, 0x44, 0x28, 0x10, 0x28, 0x44, 0x82, 0x82, 0x00, 0x00, // 'X'
+    0x00, 0x00, 0x82, 0x82, 0x44, 0x28, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00, 0x00, // 'Y'
+    0x00, 0x00, 0x7e, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x40, 0x7e, 0x00, 0x00, // 'Z'
+    0x00, 0x00, 0x3c, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x3c, 0x00, 0x00, // '['
+    0x00, 0x00, 0x80, 0x80, 0x40, 0x20, 0x10, 0x08, 0x04, 0x02, 0x02, 0x00, 0x00, // '\\'
+    0x00, 0x00, 0x78, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x78, 0x00, 0x00, // ']'
+    0x00, 0x00, 0x10, 0x28, 0x44, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '^'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xfe, 0x00, // '_'
+    0x00, 0x10, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '`'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x02, 0x3e, 0x42, 0x46, 0x3a, 0x00, 0x00, // 'a'
+    0x00, 0x00, 0x40, 0x40, 0x40, 0x5c, 0x62, 0x42, 0x42, 0x62, 0x5c, 0x00, 0x00, // 'b'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x42, 0x40, 0x40, 0x42, 0x3c, 0x00, 0x00, // 'c'
+    0x00, 0x00, 0x02, 0x02, 0x02, 0x3a, 0x46, 0x42, 0x42, 0x46, 0x3a, 0x00, 0x00, // 'd'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x42, 0x7e, 0x40, 0x42, 0x3c, 0x00, 0x00, // 'e'
+    0x00, 0x00, 0x1c, 0x22, 0x20, 0x20, 0x7c, 0x20, 0x20, 0x20, 0x20, 0x00, 0x00, // 'f'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x3a, 0x44, 0x44, 0x38, 0x40, 0x3c, 0x42, 0x3c, // 'g'
+    0x00, 0x00, 0x40, 0x40, 0x40, 0x5c, 0x62, 0x42, 0x42, 0x42, 0x42, 0x00, 0x00, // 'h'
+    0x00, 0x00, 0x00, 0x10, 0x00, 0x30, 0x10, 0x10, 0x10, 0x10, 0x7c, 0x00, 0x00, // 'i'
+    0x00, 0x00, 0x00, 0x04, 0x00, 0x0c, 0x04, 0x04, 0x04, 0x04, 0x44, 0x44, 0x38, // 'j'
+    0x00, 0x00, 0x40, 0x40, 0x40, 0x44, 0x48, 0x70, 0x48, 0x44, 0x42, 0x00, 0x00, // 'k'
+    0x00, 0x00, 0x30, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x7c, 0x00, 0x00, // 'l'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0xec, 0x92, 0x92, 0x92, 0x92, 0x82, 0x00, 0x00, // 'm'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x5c, 0x62, 0x42, 0x42, 0x42, 0x42, 0x00, 0x00, // 'n'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x42, 0x42, 0x42, 0x42, 0x3c, 0x00, 0x00, // 'o'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x5c, 0x62, 0x42, 0x62, 0x5c, 0x40, 0x40, 0x40, // 'p'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x3a, 0x46, 0x42, 0x46, 0x3a, 0x02, 0x02, 0x02, // 'q'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x5c, 0x22, 0x20, 0x20, 0x20, 0x20, 0x00, 0x00, // 'r'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x42, 0x30, 0x0c, 0x42, 0x3c, 0x00, 0x00, // 's'
+    0x00, 0x00, 0x00, 0x20, 0x20, 0x7c, 0x20, 0x20, 0x20, 0x22, 0x1c, 0x00, 0x00, // 't'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x44, 0x44, 0x44, 0x44, 0x44, 0x3a, 0x00, 0x00, // 'u'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x44, 0x44, 0x44, 0x28, 0x28, 0x10, 0x00, 0x00, // 'v'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x82, 0x82, 0x92, 0x92, 0xaa, 0x44, 0x00, 0x00, // 'w'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x42, 0x24, 0x18, 0x18, 0x24, 0x42, 0x00, 0x00, // 'x'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x42, 0x42, 0x42, 0x46, 0x3a, 0x02, 0x42, 0x3c, // 'y'
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x7e, 0x04, 0x08, 0x10, 0x20, 0x7e, 0x00, 0x00, // 'z'
+    0x00, 0x00, 0x0e, 0x10, 0x10, 0x08, 0x30, 0x08, 0x10, 0x10, 0x0e, 0x00, 0x00, // '{'
+    0x00, 0x00, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00, 0x00, // '|'
+    0x00, 0x00, 0x70, 0x08, 0x08, 0x10, 0x0c, 0x10, 0x08, 0x08, 0x70, 0x00, 0x00, // '}'
+    0x00, 0x00, 0x24, 0x54, 0x48, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '~'
+];
@@ -0,0 +1,183 @@
+//! Bitmap font text rendering onto backgrounds.
+//!
+//! The SDK draws text at fixed lines only, 4 on the monochrome LCD and a title plus
+//! 8 lines on the color LCD. A [BitmapFont](struct.BitmapFont.html) draws text onto any
+//! [Canvas](../canvas/trait.Canvas.html) at arbitrary pixel positions instead, so custom
+//! screens can mix text and graphics in the background bitmap.
+//!
+//! Four fixed-width fonts are bundled, generated from the public domain X11 misc-fixed
+//! fonts: [FONT_4X6](constant.FONT_4X6.html), [FONT_5X7](constant.FONT_5X7.html),
+//! [FONT_6X10](constant.FONT_6X10.html) and [FONT_8X13](constant.FONT_8X13.html). They
+//! cover printable ASCII, other characters are drawn as `?`.
+//!
+//! ```
+//! use logitech_lcd::canvas::MonoCanvas;
+//! use logitech_lcd::font::{Align, FONT_5X7};
+//! use logitech_lcd::{MONO_WIDTH, MONO_HEIGHT};
+//!
+//! let mut background = vec![0u8; MONO_WIDTH * MONO_HEIGHT];
+//! let width = FONT_5X7.draw(&mut MonoCanvas::new(&mut background), "CPU 42%",
+//!     MONO_WIDTH as isize / 2, 18, Align::Center, true);
+//! assert_eq!(width, FONT_5X7.measure("CPU 42%"));
+//! ```
+
+mod data;
+
+use canvas::Canvas;
+
+/// Horizontal text alignment relative to the x coordinate passed to
+/// [BitmapFont::draw](struct.BitmapFont.html#method.draw).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    /// Text starts at x.
+    Left,
+    /// Text is centered on x.
+    Center,
+    /// Text ends at x.
+    Right,
+}
+
+/// A fixed-width bitmap font covering printable ASCII.
+#[derive(Debug, Clone, Copy)]
+pub struct BitmapFont {
+    width: usize,
+    height: usize,
+    baseline: usize,
+    glyphs: &'static [u8],
+}
+
+/// 4x6 pixel font, fits 40 characters on a monochrome line.
+pub const FONT_4X6: BitmapFont = BitmapFont {
+    width: 4,
+    height: 6,
+    baseline: 4,
+    glyphs: &data::GLYPHS_4X6,
+};
+
+/// 5x7 pixel font.
+pub const FONT_5X7: BitmapFont = BitmapFont {
+    width: 5,
+    height: 7,
+    baseline: 5,
+    glyphs: &data::GLYPHS_5X7,
+};
+
+/// 6x10 pixel font, about the size of the SDK's monochrome text.
+pub const FONT_6X10: BitmapFont = BitmapFont {
+    width: 6,
+    height: 10,
+    baseline: 7,
+    glyphs: &data::GLYPHS_6X10,
+};
+
+/// 8x13 pixel font, for the color LCD.
+pub const FONT_8X13: BitmapFont = BitmapFont {
+    width: 8,
+    height: 13,
+    baseline: 10,
+    glyphs: &data::GLYPHS_8X13,
+};
+
+const FIRST_GLYPH: u32 = ' ' as u32;
+const LAST_GLYPH: u32 = '~' as u32;
+
+impl BitmapFont {
+    /// Creates a font from glyph data.
+    ///
+    /// Parameters:
+    /// - glyphs: One glyph per printable ASCII character, `' '` to `'~'`. Each glyph is
+    ///   `height` rows top to bottom, each row `ceil(width / 8)` bytes with the leftmost
+    ///   pixel in the most significant bit.
+    /// - baseline: Row of the baseline, counted from the top.
+    ///
+    /// Panics:
+    /// - If glyphs' length does not match 95 glyphs of the given size.
+    ///
+    pub fn new(width: usize, height: usize, baseline: usize, glyphs: &'static [u8])
+        -> BitmapFont
+    {
+        assert_eq!(glyphs.len(), width.div_ceil(8) * height * 95);
+        BitmapFont { width, height, baseline, glyphs }
+    }
+
+    /// Width of each character in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height of each line in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Row of the baseline, counted from the top of a line.
+    pub fn baseline(&self) -> usize {
+        self.baseline
+    }
+
+    /// Returns the glyph rows for a character, or `None` if the font doesn't cover it.
+    pub fn glyph(&self, c: char) -> Option<&'static [u8]> {
+        let c = c as u32;
+        if !(FIRST_GLYPH..=LAST_GLYPH).contains(&c) {
+            return None;
+        }
+        let size = self.width.div_ceil(8) * self.height;
+        let start = (c - FIRST_GLYPH) as usize * size;
+        let glyphs: &'static [u8] = self.glyphs;
+        Some(&glyphs[start..start + size])
+    }
+
+    /// Width in pixels of the widest line of `text`.
+    pub fn measure(&self, text: &str) -> usize {
+        text.lines()
+            .map(|line| line.chars().count() * self.width)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Draws text onto a canvas, only touching the pixels of the glyphs.
+    ///
+    /// Lines are separated by `'\n'` and aligned individually. Everything outside the
+    /// canvas' clip rect is clipped.
+    ///
+    /// Parameters:
+    /// - x: Horizontal anchor of each line, see [Align](enum.Align.html).
+    /// - y: Top of the first line.
+    /// - color: Pixel value for the glyphs.
+    ///
+    /// Return value:
+    /// The width in pixels of the widest line, same as [measure](#method.measure).
+    ///
+    pub fn draw<C: Canvas>(&self, canvas: &mut C, text: &str, x: isize, y: isize, align: Align,
+        color: C::Pixel) -> usize
+    {
+        let bytes_per_row = self.width.div_ceil(8);
+        let fallback = self.glyph('?').unwrap();
+
+        for (line_index, line) in text.lines().enumerate() {
+            let line_width = (line.chars().count() * self.width) as isize;
+            let left = match align {
+                Align::Left => x,
+                Align::Center => x - line_width / 2,
+                Align::Right => x - line_width,
+            };
+            let top = y + (line_index * self.height) as isize;
+
+            for (i, c) in line.chars().enumerate() {
+                let c = if c == '\t' { ' ' } else { c };
+                let glyph = self.glyph(c).unwrap_or(fallback);
+                let gx = left + (i * self.width) as isize;
+
+                for (row, bits) in glyph.chunks(bytes_per_row).enumerate() {
+                    for col in 0..self.width {
+                        if bits[col / 8] & (0x80 >> (col % 8)) != 0 {
+                            canvas.put_pixel(gx + col as isize, top + row as isize, color);
+                        }
+                    }
+                }
+            }
+        }
+
+        self.measure(text)
+    }
+}
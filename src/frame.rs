@@ -0,0 +1,349 @@
+//! Atomic screen updates.
+//!
+//! A screen is usually drawn with several calls, a background, a title and a few text
+//! lines, followed by [update](../struct.Driver.html#method.update). If one of the calls
+//! fails halfway, the screen is left half drawn. A [Frame](struct.Frame.html) collects the
+//! content first, validates all of it before anything is sent, and rolls the screen back to
+//! its previous content if a call into the SDK fails.
+//!
+//! ```no_run
+//! use logitech_lcd::color::Rgb;
+//! use logitech_lcd::frame::Frame;
+//!
+//! let mut driver = logitech_lcd::Driver::init_color("Frame").unwrap();
+//! let background = vec![0u8; logitech_lcd::COLOR_WIDTH * logitech_lcd::COLOR_HEIGHT * 4];
+//!
+//! Frame::new()
+//!     .color_background(&background)
+//!     .color_title("Status", Rgb::new(255, 255, 255))
+//!     .color_text(0, "All systems nominal", Rgb::new(0, 255, 0))
+//!     .apply(&mut driver)
+//!     .unwrap();
+//! ```
+
+use color::Rgb;
+use state::ScreenState;
+use {sys, Driver, Error, COLOR_WIDTH, COLOR_HEIGHT, MONO_WIDTH, MONO_HEIGHT};
+
+/// Content for both screens, applied as one unit. Content that is not set is left as is.
+#[derive(Debug, Clone, Default)]
+pub struct Frame<'a> {
+    mono_background: Option<&'a [u8]>,
+    mono_text: Vec<(usize, &'a str)>,
+    color_background: Option<&'a [u8]>,
+    color_title: Option<(&'a str, Rgb)>,
+    color_text: Vec<(usize, &'a str, Rgb)>,
+}
+
+/// What a frame sends to. Implemented by [Driver](../struct.Driver.html), and by a fake in
+/// the tests.
+trait Target {
+    fn state(&self) -> &ScreenState;
+    fn set_mono_background(&mut self, mono_bitmap: &[u8]) -> Result<(), Error>;
+    fn set_mono_text(&mut self, line_number: usize, text: &str) -> Result<(), Error>;
+    fn set_color_background(&mut self, color_bitmap: &[u8]) -> Result<(), Error>;
+    fn set_color_title(&mut self, text: &str, color: Rgb) -> Result<(), Error>;
+    fn set_color_text(&mut self, line_number: usize, text: &str, color: Rgb)
+        -> Result<(), Error>;
+}
+
+impl Target for Driver {
+    fn state(&self) -> &ScreenState {
+        &self.state
+    }
+
+    fn set_mono_background(&mut self, mono_bitmap: &[u8]) -> Result<(), Error> {
+        Driver::set_mono_background(self, mono_bitmap)
+    }
+
+    fn set_mono_text(&mut self, line_number: usize, text: &str) -> Result<(), Error> {
+        Driver::set_mono_text(self, line_number, text)
+    }
+
+    fn set_color_background(&mut self, color_bitmap: &[u8]) -> Result<(), Error> {
+        Driver::set_color_background(self, color_bitmap)
+    }
+
+    fn set_color_title(&mut self, text: &str, color: Rgb) -> Result<(), Error> {
+        Driver::set_color_title(self, text, color.r, color.g, color.b)
+    }
+
+    fn set_color_text(&mut self, line_number: usize, text: &str, color: Rgb)
+        -> Result<(), Error>
+    {
+        Driver::set_color_text(self, line_number, text, color.r, color.g, color.b)
+    }
+}
+
+/// The previous content of something a frame changed.
+enum Undo {
+    MonoBackground(Option<Vec<u8>>),
+    MonoText(usize, Option<String>),
+    ColorBackground(Option<Vec<u8>>),
+    ColorTitle(Option<(String, Rgb)>),
+    ColorText(usize, Option<(String, Rgb)>),
+}
+
+impl<'a> Frame<'a> {
+    /// Creates an empty frame.
+    pub fn new() -> Frame<'a> {
+        Frame::default()
+    }
+
+    /// Sets the monochrome background, see
+    /// [set_mono_background](../struct.Driver.html#method.set_mono_background).
+    pub fn mono_background(mut self, mono_bitmap: &'a [u8]) -> Frame<'a> {
+        self.mono_background = Some(mono_bitmap);
+        self
+    }
+
+    /// Sets a monochrome text line, see
+    /// [set_mono_text](../struct.Driver.html#method.set_mono_text).
+    pub fn mono_text(mut self, line_number: usize, text: &'a str) -> Frame<'a> {
+        self.mono_text.push((line_number, text));
+        self
+    }
+
+    /// Sets the color background, see
+    /// [set_color_background](../struct.Driver.html#method.set_color_background).
+    pub fn color_background(mut self, color_bitmap: &'a [u8]) -> Frame<'a> {
+        self.color_background = Some(color_bitmap);
+        self
+    }
+
+    /// Sets the color title, see
+    /// [set_color_title](../struct.Driver.html#method.set_color_title).
+    pub fn color_title(mut self, text: &'a str, color: Rgb) -> Frame<'a> {
+        self.color_title = Some((text, color));
+        self
+    }
+
+    /// Sets a color text line, see
+    /// [set_color_text](../struct.Driver.html#method.set_color_text).
+    pub fn color_text(mut self, line_number: usize, text: &'a str, color: Rgb) -> Frame<'a> {
+        self.color_text.push((line_number, text, color));
+        self
+    }
+
+    /// Checks that the frame can be applied to the driver without sending anything.
+    ///
+    /// Return value:
+    /// [Error::InvalidFrame](../enum.Error.html) for content of an lcd type the driver was
+    /// not initialized with, backgrounds of the wrong size and line numbers out of range,
+    /// [Error::NullCharacter](../enum.Error.html) for text containing NUL characters.
+    ///
+    /// The length of text is not checked. The SDK accepts text of any length and cuts off
+    /// what doesn't fit the line, and how much fits depends on the characters.
+    ///
+    pub fn validate(&self, driver: &Driver) -> Result<(), Error> {
+        let has_mono = self.mono_background.is_some() || !self.mono_text.is_empty();
+        let has_color = self.color_background.is_some() || self.color_title.is_some() ||
+            !self.color_text.is_empty();
+
+        if has_mono && !driver.type_flags.contains(sys::LcdType::MONO) {
+            return Err(Error::InvalidFrame("Mono content for a driver without mono support."));
+        }
+        if has_color && !driver.type_flags.contains(sys::LcdType::COLOR) {
+            return Err(Error::InvalidFrame("Color content for a driver without color support."));
+        }
+        if self.mono_background.is_some_and(|b| b.len() != MONO_WIDTH * MONO_HEIGHT) {
+            return Err(Error::InvalidFrame("Mono background is not 160x43 bytes."));
+        }
+        if self.color_background.is_some_and(|b| b.len() != COLOR_WIDTH * COLOR_HEIGHT * 4) {
+            return Err(Error::InvalidFrame("Color background is not 320x240x4 bytes."));
+        }
+        if self.mono_text.iter().any(|&(line, _)| line >= 4) {
+            return Err(Error::InvalidFrame("Mono line number larger than 3."));
+        }
+        if self.color_text.iter().any(|&(line, _, _)| line >= 8) {
+            return Err(Error::InvalidFrame("Color line number larger than 7."));
+        }
+
+        let mut texts = self.mono_text.iter().map(|&(_, t)| t)
+            .chain(self.color_title.iter().map(|&(t, _)| t))
+            .chain(self.color_text.iter().map(|&(_, t, _)| t));
+        if texts.any(|t| t.contains('\0')) {
+            return Err(Error::NullCharacter);
+        }
+
+        Ok(())
+    }
+
+    /// Validates the frame, sends it and calls [update](../struct.Driver.html#method.update).
+    ///
+    /// Nothing is sent if validation fails. If a call into the SDK fails, everything the
+    /// frame already changed is set back to its previous content, as far as the driver
+    /// knows it, and the error of the failed call is returned without calling update.
+    pub fn apply(&self, driver: &mut Driver) -> Result<(), Error> {
+        self.validate(driver)?;
+
+        let mut undo = Vec::new();
+        match self.send(driver, &mut undo) {
+            Ok(()) => {
+                driver.update();
+                Ok(())
+            },
+            Err(e) => {
+                rollback(driver, undo);
+                Err(e)
+            },
+        }
+    }
+
+    fn send<T: Target>(&self, driver: &mut T, undo: &mut Vec<Undo>) -> Result<(), Error> {
+        if let Some(bitmap) = self.mono_background {
+            undo.push(Undo::MonoBackground(driver.state().mono_background.clone()));
+            driver.set_mono_background(bitmap)?;
+        }
+        for &(line, text) in self.mono_text.iter() {
+            undo.push(Undo::MonoText(line, driver.state().mono_text[line].clone()));
+            driver.set_mono_text(line, text)?;
+        }
+        if let Some(bitmap) = self.color_background {
+            undo.push(Undo::ColorBackground(driver.state().color_background.clone()));
+            driver.set_color_background(bitmap)?;
+        }
+        if let Some((text, c)) = self.color_title {
+            undo.push(Undo::ColorTitle(driver.state().color_title.clone()));
+            driver.set_color_title(text, c)?;
+        }
+        for &(line, text, c) in self.color_text.iter() {
+            undo.push(Undo::ColorText(line, driver.state().color_text[line].clone()));
+            driver.set_color_text(line, text, c)?;
+        }
+        Ok(())
+    }
+}
+
+/// Restores previous content newest first. Content the driver never knew is left as is,
+/// and errors are ignored since the original error is reported.
+fn rollback<T: Target>(driver: &mut T, undo: Vec<Undo>) {
+    for step in undo.into_iter().rev() {
+        let _ = match step {
+            Undo::MonoBackground(Some(bitmap)) => driver.set_mono_background(&bitmap),
+            Undo::MonoText(line, Some(text)) => driver.set_mono_text(line, &text),
+            Undo::ColorBackground(Some(bitmap)) => driver.set_color_background(&bitmap),
+            Undo::ColorTitle(Some((text, c))) => driver.set_color_title(&text, c),
+            Undo::ColorText(line, Some((text, c))) => driver.set_color_text(line, &text, c),
+            _ => Ok(()),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rollback, Frame, Target};
+    use color::Rgb;
+    use state::ScreenState;
+    use Error;
+
+    /// Keeps the shadow state like the driver does, and fails the call numbered `fail_at`.
+    struct Fake {
+        state: ScreenState,
+        calls: usize,
+        fail_at: usize,
+    }
+
+    impl Fake {
+        fn call(&mut self) -> bool {
+            self.calls += 1;
+            self.calls != self.fail_at
+        }
+    }
+
+    impl Target for Fake {
+        fn state(&self) -> &ScreenState {
+            &self.state
+        }
+
+        fn set_mono_background(&mut self, mono_bitmap: &[u8]) -> Result<(), Error> {
+            let ok = self.call();
+            self.state.mono_background = if ok { Some(mono_bitmap.to_vec()) } else { None };
+            if ok { Ok(()) } else { Err(Error::MonoBackground) }
+        }
+
+        fn set_mono_text(&mut self, line_number: usize, text: &str) -> Result<(), Error> {
+            let ok = self.call();
+            self.state.mono_text[line_number] = if ok { Some(text.to_owned()) } else { None };
+            if ok { Ok(()) } else { Err(Error::MonoText) }
+        }
+
+        fn set_color_background(&mut self, color_bitmap: &[u8]) -> Result<(), Error> {
+            let ok = self.call();
+            self.state.color_background = if ok { Some(color_bitmap.to_vec()) } else { None };
+            if ok { Ok(()) } else { Err(Error::ColorBackground) }
+        }
+
+        fn set_color_title(&mut self, text: &str, color: Rgb) -> Result<(), Error> {
+            let ok = self.call();
+            self.state.color_title = if ok { Some((text.to_owned(), color)) } else { None };
+            if ok { Ok(()) } else { Err(Error::ColorTitle) }
+        }
+
+        fn set_color_text(&mut self, line_number: usize, text: &str, color: Rgb)
+            -> Result<(), Error>
+        {
+            let ok = self.call();
+            self.state.color_text[line_number] =
+                if ok { Some((text.to_owned(), color)) } else { None };
+            if ok { Ok(()) } else { Err(Error::ColorText) }
+        }
+    }
+
+    fn fake(fail_at: usize) -> Fake {
+        let mut fake = Fake { state: ScreenState::default(), calls: 0, fail_at };
+        fake.state.mono_background = Some(vec![1; 4]);
+        fake.state.mono_text[0] = Some("old mono".to_owned());
+        fake.state.color_title = Some(("old title".to_owned(), Rgb::new(1, 2, 3)));
+        fake.state.color_text[2] = Some(("old line".to_owned(), Rgb::new(4, 5, 6)));
+        fake
+    }
+
+    fn send(target: &mut Fake) -> Result<(), Error> {
+        let white = Rgb::new(255, 255, 255);
+        let frame = Frame::new()
+            .mono_background(&[2; 4])
+            .mono_text(0, "new mono")
+            .mono_text(1, "second")
+            .color_title("new title", white)
+            .color_text(2, "new line", white);
+        let mut undo = Vec::new();
+        let ret = frame.send(target, &mut undo);
+        if ret.is_err() {
+            rollback(target, undo);
+        }
+        ret
+    }
+
+    #[test]
+    fn sends_everything() {
+        let mut target = fake(0);
+        send(&mut target).unwrap();
+        assert_eq!(target.calls, 5);
+        assert_eq!(target.state.mono_background, Some(vec![2; 4]));
+        assert_eq!(target.state.mono_text[1], Some("second".to_owned()));
+        assert_eq!(target.state.color_text[2].as_ref().unwrap().0, "new line");
+    }
+
+    #[test]
+    fn failure_restores_earlier_content() {
+        for fail_at in 1..6 {
+            let mut target = fake(fail_at);
+            assert!(send(&mut target).is_err());
+            let state = &target.state;
+            assert_eq!(state.mono_background, Some(vec![1; 4]));
+            assert_eq!(state.mono_text[0], Some("old mono".to_owned()));
+            assert_eq!(state.color_title, Some(("old title".to_owned(), Rgb::new(1, 2, 3))));
+            assert_eq!(state.color_text[2], Some(("old line".to_owned(), Rgb::new(4, 5, 6))));
+        }
+    }
+
+    #[test]
+    fn unknown_content_is_left_as_is() {
+        // The second mono line was never set, so it is not restored and stays as sent.
+        let mut target = fake(4);
+        assert!(send(&mut target).is_err());
+        assert_eq!(target.state.mono_text[1], Some("second".to_owned()));
+        assert_eq!(target.calls, 4 + 3);
+    }
+}
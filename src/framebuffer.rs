@@ -0,0 +1,297 @@
+//! Owned background buffers.
+//!
+//! [MonoFramebuffer](struct.MonoFramebuffer.html) and
+//! [ColorFramebuffer](struct.ColorFramebuffer.html) own a full screen background in the
+//! format the SDK expects, implement [Canvas](../canvas/trait.Canvas.html) so they can be
+//! drawn onto directly, and upload themselves to a [Driver](../struct.Driver.html) with
+//! `flush`.
+//!
+//! Framebuffers track the region that changed since they were last uploaded.
+//! `flush_dirty` skips the upload when nothing changed, and otherwise only sends the
+//! rows up to the last changed one through the partial background functions of the SDK.
+//! Changes near the bottom of the screen still send the whole background.
+//!
+//! ```no_run
+//! use logitech_lcd::canvas::Canvas;
+//! use logitech_lcd::color::Argb;
+//! use logitech_lcd::framebuffer::ColorFramebuffer;
+//!
+//! let mut driver = logitech_lcd::Driver::init_color("Framebuffer").unwrap();
+//! let mut frame = ColorFramebuffer::new();
+//! frame.clear(Argb::opaque(0, 0, 64));
+//! frame.flush(&mut driver).unwrap();
+//! driver.update();
+//!
+//! // Only the first 20 rows are sent.
+//! frame.put_pixel(10, 19, Argb::opaque(255, 255, 255));
+//! frame.flush_dirty(&mut driver).unwrap();
+//! driver.update();
+//! ```
+
+use canvas::{Canvas, Rect};
+use color::Argb;
+use {Driver, Error, COLOR_WIDTH, COLOR_HEIGHT, MONO_WIDTH, MONO_HEIGHT};
+
+/// A monochrome background, 160x43 pixels with 8 bits per pixel.
+#[derive(Debug, Clone)]
+pub struct MonoFramebuffer {
+    buf: Vec<u8>,
+    dirty: Rect,
+}
+
+impl MonoFramebuffer {
+    /// Creates a framebuffer with all pixels off. The whole framebuffer starts out dirty.
+    pub fn new() -> MonoFramebuffer {
+        MonoFramebuffer::from_bytes(vec![0; MONO_WIDTH * MONO_HEIGHT])
+    }
+
+    /// Wraps an existing monochrome background. The whole framebuffer starts out dirty.
+    ///
+    /// Panics:
+    /// - If buf's length is not 160x43 bytes.
+    ///
+    pub fn from_bytes(buf: Vec<u8>) -> MonoFramebuffer {
+        assert_eq!(buf.len(), MONO_WIDTH * MONO_HEIGHT);
+        MonoFramebuffer { buf, dirty: Rect::new(0, 0, MONO_WIDTH, MONO_HEIGHT) }
+    }
+
+    /// The background in the format of
+    /// [set_mono_background](../struct.Driver.html#method.set_mono_background).
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Returns the underlying buffer.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Uploads the framebuffer as the monochrome background.
+    ///
+    /// Panics:
+    /// - If Lcd was initialized without mono support.
+    ///
+    pub fn flush(&mut self, driver: &mut Driver) -> Result<(), Error> {
+        driver.set_mono_background(&self.buf)?;
+        self.mark_clean();
+        Ok(())
+    }
+
+    /// Uploads the framebuffer if it changed since the last upload.
+    ///
+    /// The SDK's partial background functions have no offset parameter and always write
+    /// from the top left corner, so the rows from the top of the screen to the bottom of
+    /// the [dirty rect](#method.dirty_rect) are sent, including unchanged rows above it.
+    /// Only the rows below the dirty rect are skipped, so this saves nothing for changes
+    /// low on the screen. When the dirty rect reaches into the last quarter of the screen
+    /// the whole framebuffer is sent with [flush](#method.flush) instead. Otherwise this
+    /// resets the mono partial background write position first, the other lcd's is left
+    /// alone.
+    ///
+    /// Panics:
+    /// - If Lcd was initialized without mono support.
+    ///
+    pub fn flush_dirty(&mut self, driver: &mut Driver) -> Result<(), Error> {
+        let dirty = match self.dirty_rect() {
+            Some(dirty) => dirty,
+            None => return Ok(()),
+        };
+        if (dirty.y + dirty.height) * 4 > MONO_HEIGHT * 3 {
+            return self.flush(driver);
+        }
+        let end = (dirty.y + dirty.height) * MONO_WIDTH;
+        driver.reset_mono_background()?;
+        driver.set_mono_background_partial(&self.buf[..end])?;
+        self.mark_clean();
+        Ok(())
+    }
+
+    /// The smallest rectangle covering all pixels changed since the last upload, `None` if
+    /// nothing changed.
+    pub fn dirty_rect(&self) -> Option<Rect> {
+        if self.dirty.is_empty() { None } else { Some(self.dirty) }
+    }
+
+    /// Adds a region to the dirty rect, clipped to the screen.
+    pub fn mark_dirty(&mut self, rect: Rect) {
+        let rect = rect.intersect(&Rect::new(0, 0, MONO_WIDTH, MONO_HEIGHT));
+        self.dirty = self.dirty.union(&rect);
+    }
+
+    /// Forgets all changes, for example after uploading the framebuffer by other means.
+    pub fn mark_clean(&mut self) {
+        self.dirty = Rect::default();
+    }
+}
+
+impl Default for MonoFramebuffer {
+    fn default() -> MonoFramebuffer {
+        MonoFramebuffer::new()
+    }
+}
+
+impl Canvas for MonoFramebuffer {
+    type Pixel = bool;
+
+    fn width(&self) -> usize {
+        MONO_WIDTH
+    }
+
+    fn height(&self) -> usize {
+        MONO_HEIGHT
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> bool {
+        assert!(x < MONO_WIDTH && y < MONO_HEIGHT);
+        self.buf[y * MONO_WIDTH + x] >= 128
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, on: bool) {
+        assert!(x < MONO_WIDTH && y < MONO_HEIGHT);
+        let value = if on { 255 } else { 0 };
+        if self.buf[y * MONO_WIDTH + x] != value {
+            self.buf[y * MONO_WIDTH + x] = value;
+            self.mark_dirty(Rect::new(x, y, 1, 1));
+        }
+    }
+}
+
+/// A color background, 320x240 pixels in BGRA order.
+#[derive(Debug, Clone)]
+pub struct ColorFramebuffer {
+    buf: Vec<u8>,
+    dirty: Rect,
+}
+
+impl ColorFramebuffer {
+    /// Creates a framebuffer with all pixels transparent black. The whole framebuffer
+    /// starts out dirty.
+    pub fn new() -> ColorFramebuffer {
+        ColorFramebuffer::from_bytes(vec![0; COLOR_WIDTH * COLOR_HEIGHT * 4])
+    }
+
+    /// Wraps an existing color background. The whole framebuffer starts out dirty.
+    ///
+    /// Panics:
+    /// - If buf's length is not 320x240x4 bytes.
+    ///
+    pub fn from_bytes(buf: Vec<u8>) -> ColorFramebuffer {
+        assert_eq!(buf.len(), COLOR_WIDTH * COLOR_HEIGHT * 4);
+        ColorFramebuffer { buf, dirty: Rect::new(0, 0, COLOR_WIDTH, COLOR_HEIGHT) }
+    }
+
+    /// The background in the format of
+    /// [set_color_background](../struct.Driver.html#method.set_color_background).
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Returns the underlying buffer.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Uploads the framebuffer as the color background.
+    ///
+    /// Panics:
+    /// - If Lcd was initialized without color support.
+    ///
+    pub fn flush(&mut self, driver: &mut Driver) -> Result<(), Error> {
+        driver.set_color_background(&self.buf)?;
+        self.mark_clean();
+        Ok(())
+    }
+
+    /// Uploads the framebuffer if it changed since the last upload.
+    ///
+    /// The SDK's partial background functions have no offset parameter and always write
+    /// from the top left corner, so the rows from the top of the screen to the bottom of
+    /// the [dirty rect](#method.dirty_rect) are sent, including unchanged rows above it.
+    /// Only the rows below the dirty rect are skipped, so this saves nothing for changes
+    /// low on the screen. When the dirty rect reaches into the last quarter of the screen
+    /// the whole framebuffer is sent with [flush](#method.flush) instead. Otherwise this
+    /// resets the color partial background write position first, the other lcd's is left
+    /// alone.
+    ///
+    /// Panics:
+    /// - If Lcd was initialized without color support.
+    ///
+    pub fn flush_dirty(&mut self, driver: &mut Driver) -> Result<(), Error> {
+        let dirty = match self.dirty_rect() {
+            Some(dirty) => dirty,
+            None => return Ok(()),
+        };
+        if (dirty.y + dirty.height) * 4 > COLOR_HEIGHT * 3 {
+            return self.flush(driver);
+        }
+        let end = (dirty.y + dirty.height) * COLOR_WIDTH * 4;
+        driver.reset_color_background()?;
+        driver.set_color_background_partial(&self.buf[..end])?;
+        self.mark_clean();
+        Ok(())
+    }
+
+    /// The smallest rectangle covering all pixels changed since the last upload, `None` if
+    /// nothing changed.
+    pub fn dirty_rect(&self) -> Option<Rect> {
+        if self.dirty.is_empty() { None } else { Some(self.dirty) }
+    }
+
+    /// Adds a region to the dirty rect, clipped to the screen.
+    pub fn mark_dirty(&mut self, rect: Rect) {
+        let rect = rect.intersect(&Rect::new(0, 0, COLOR_WIDTH, COLOR_HEIGHT));
+        self.dirty = self.dirty.union(&rect);
+    }
+
+    /// Forgets all changes, for example after uploading the framebuffer by other means.
+    pub fn mark_clean(&mut self) {
+        self.dirty = Rect::default();
+    }
+}
+
+impl Default for ColorFramebuffer {
+    fn default() -> ColorFramebuffer {
+        ColorFramebuffer::new()
+    }
+}
+
+impl Canvas for ColorFramebuffer {
+    type Pixel = Argb;
+
+    fn width(&self) -> usize {
+        COLOR_WIDTH
+    }
+
+    fn height(&self) -> usize {
+        COLOR_HEIGHT
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> Argb {
+        assert!(x < COLOR_WIDTH && y < COLOR_HEIGHT);
+        let i = (y * COLOR_WIDTH + x) * 4;
+        Argb::from_bgra(&self.buf[i..i + 4])
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, pixel: Argb) {
+        assert!(x < COLOR_WIDTH && y < COLOR_HEIGHT);
+        let i = (y * COLOR_WIDTH + x) * 4;
+        let bgra = pixel.to_bgra();
+        if self.buf[i..i + 4] != bgra {
+            self.buf[i..i + 4].copy_from_slice(&bgra);
+            self.mark_dirty(Rect::new(x, y, 1, 1));
+        }
+    }
+
+    fn blend_pixel(&mut self, x: usize, y: usize, pixel: Argb, coverage: u8) {
+        let dst = self.pixel(x, y);
+        self.set_pixel(x, y, pixel.over(dst, coverage));
+    }
+
+    fn clear(&mut self, pixel: Argb) {
+        let bgra = pixel.to_bgra();
+        for px in self.buf.chunks_mut(4) {
+            px.copy_from_slice(&bgra);
+        }
+        self.mark_dirty(Rect::new(0, 0, COLOR_WIDTH, COLOR_HEIGHT));
+    }
+}
@@ -0,0 +1,182 @@
+//! Bundled icons for status displays.
+//!
+//! Every [Icon](enum.Icon.html) comes in four [sizes](enum.IconSize.html): 8x8 and 16x16
+//! drawn pixel exact for the monochrome LCD, and 24x24 and 32x32 with antialiased edges
+//! for the color LCD. Icons are masks drawn in any color onto any
+//! [Canvas](../canvas/trait.Canvas.html) at any position, only touching the pixels they
+//! cover. On monochrome canvases the antialiased sizes are thresholded.
+//!
+//! ```
+//! use logitech_lcd::canvas::{ColorCanvas, MonoCanvas};
+//! use logitech_lcd::color::Rgb;
+//! use logitech_lcd::icon::{Icon, IconSize};
+//! use logitech_lcd::{COLOR_WIDTH, COLOR_HEIGHT, MONO_WIDTH, MONO_HEIGHT};
+//!
+//! let mut mono = vec![0u8; MONO_WIDTH * MONO_HEIGHT];
+//! Icon::Battery.draw(&mut MonoCanvas::new(&mut mono), IconSize::Px8, 150, 1, true);
+//!
+//! let mut color = vec![0u8; COLOR_WIDTH * COLOR_HEIGHT * 4];
+//! {
+//!     let mut canvas = ColorCanvas::new(&mut color);
+//!     Icon::Wifi.draw_tinted(&mut canvas, IconSize::Px32, 280, 4, Rgb::new(0, 200, 255));
+//!     Icon::Warning.draw_tinted(&mut canvas, IconSize::Px24, 8, 8, "orange".parse().unwrap());
+//! }
+//! ```
+
+mod data;
+
+use canvas::Canvas;
+use color::{Argb, Rgb};
+use packed::PackedMonoBitmap;
+
+/// A bundled icon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Icon {
+    /// A full battery.
+    Battery,
+    /// Wireless signal.
+    Wifi,
+    /// A processor chip.
+    Cpu,
+    /// A clock face.
+    Clock,
+    /// A speaker with sound waves.
+    Volume,
+    /// A crossed out speaker.
+    Mute,
+    /// A right-pointing triangle.
+    Play,
+    /// Two vertical bars.
+    Pause,
+    /// An exclamation mark in a triangle.
+    Warning,
+    /// An arrow pointing up.
+    ArrowUp,
+    /// An arrow pointing down.
+    ArrowDown,
+    /// An arrow pointing left.
+    ArrowLeft,
+    /// An arrow pointing right.
+    ArrowRight,
+}
+
+/// The size of an icon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IconSize {
+    /// 8x8 pixels, for the monochrome LCD.
+    Px8,
+    /// 16x16 pixels, for the monochrome LCD.
+    Px16,
+    /// 24x24 pixels with antialiased edges, for the color LCD.
+    Px24,
+    /// 32x32 pixels with antialiased edges, for the color LCD.
+    Px32,
+}
+
+impl IconSize {
+    /// Width and height in pixels.
+    pub fn pixels(self) -> usize {
+        match self {
+            IconSize::Px8 => 8,
+            IconSize::Px16 => 16,
+            IconSize::Px24 => 24,
+            IconSize::Px32 => 32,
+        }
+    }
+}
+
+impl Icon {
+    /// Every icon, in declaration order.
+    pub const ALL: [Icon; 13] = [
+        Icon::Battery, Icon::Wifi, Icon::Cpu, Icon::Clock, Icon::Volume, Icon::Mute,
+        Icon::Play, Icon::Pause, Icon::Warning, Icon::ArrowUp, Icon::ArrowDown,
+        Icon::ArrowLeft, Icon::ArrowRight,
+    ];
+
+    /// The icon's name in snake case, like `"arrow_up"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Icon::Battery => "battery",
+            Icon::Wifi => "wifi",
+            Icon::Cpu => "cpu",
+            Icon::Clock => "clock",
+            Icon::Volume => "volume",
+            Icon::Mute => "mute",
+            Icon::Play => "play",
+            Icon::Pause => "pause",
+            Icon::Warning => "warning",
+            Icon::ArrowUp => "arrow_up",
+            Icon::ArrowDown => "arrow_down",
+            Icon::ArrowLeft => "arrow_left",
+            Icon::ArrowRight => "arrow_right",
+        }
+    }
+
+    /// Looks up an icon by [name](#method.name).
+    pub fn from_name(name: &str) -> Option<Icon> {
+        Icon::ALL.iter().cloned().find(|icon| icon.name() == name)
+    }
+
+    /// Coverage of a pixel, 0 outside the icon and 255 inside. Only the antialiased sizes
+    /// have values in between.
+    ///
+    /// Panics:
+    /// - If the coordinate is outside the icon.
+    ///
+    pub fn coverage(self, size: IconSize, x: usize, y: usize) -> u8 {
+        let pixels = size.pixels();
+        assert!(x < pixels && y < pixels);
+        let index = self as usize;
+
+        match size {
+            IconSize::Px8 => bit(&data::MONO_8[index * 8..], 1, x, y),
+            IconSize::Px16 => bit(&data::MONO_16[index * 32..], 2, x, y),
+            IconSize::Px24 => data::COLOR_24[index * 24 * 24 + y * 24 + x],
+            IconSize::Px32 => data::COLOR_32[index * 32 * 32 + y * 32 + x],
+        }
+    }
+
+    /// The icon as a bitmap, pixels with a coverage >= 128 are on. See
+    /// [Markup::icon](../markup/struct.Markup.html#method.icon).
+    pub fn bitmap(self, size: IconSize) -> PackedMonoBitmap {
+        let pixels = size.pixels();
+        let mut bitmap = PackedMonoBitmap::new(pixels, pixels);
+        for y in 0..pixels {
+            for x in 0..pixels {
+                bitmap.set(x, y, self.coverage(size, x, y) >= 128);
+            }
+        }
+        bitmap
+    }
+
+    /// Draws the icon with its top left corner at x, y. Pixels outside the canvas' clip
+    /// rect are clipped.
+    pub fn draw<C: Canvas>(self, canvas: &mut C, size: IconSize, x: isize, y: isize,
+        color: C::Pixel)
+    {
+        let pixels = size.pixels();
+        for row in 0..pixels {
+            for col in 0..pixels {
+                let coverage = self.coverage(size, col, row);
+                if coverage > 0 {
+                    canvas.put_blended(x + col as isize, y + row as isize, color, coverage);
+                }
+            }
+        }
+    }
+
+    /// Draws the icon in an opaque color onto a color canvas, see [draw](#method.draw).
+    pub fn draw_tinted<C>(self, canvas: &mut C, size: IconSize, x: isize, y: isize, tint: Rgb)
+        where C: Canvas<Pixel = Argb>
+    {
+        self.draw(canvas, size, x, y, Argb::from(tint));
+    }
+}
+
+/// Reads a pixel of a 1-bit icon as coverage.
+fn bit(rows: &[u8], bytes_per_row: usize, x: usize, y: usize) -> u8 {
+    match rows[y * bytes_per_row + x / 8] & (0x80 >> (x % 8)) {
+        0 => 0,
+        _ => 255,
+    }
+}
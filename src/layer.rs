@@ -0,0 +1,332 @@
+//! Layer compositing.
+//!
+//! The SDK takes one background per screen. A [LayerStack](struct.LayerStack.html) keeps
+//! overlays like status bars, toasts and cursors as separate bitmaps, each with a
+//! position, z-order and visibility, and composites them into a background on demand, so
+//! moving or hiding an overlay doesn't require redrawing everything else.
+//!
+//! [ColorLayer](struct.ColorLayer.html)s are BGRA bitmaps with straight or premultiplied
+//! alpha and an opacity, blended with the source-over operator.
+//! [MonoLayer](struct.MonoLayer.html)s are packed 1-bit bitmaps combined with a
+//! [BlitOp](../packed/enum.BlitOp.html), like OR to draw, XOR to invert or AND to mask.
+//!
+//! ```
+//! use logitech_lcd::canvas::Canvas;
+//! use logitech_lcd::color::Argb;
+//! use logitech_lcd::framebuffer::ColorFramebuffer;
+//! use logitech_lcd::layer::{Alpha, ColorLayer, LayerStack};
+//!
+//! let mut layers = LayerStack::new();
+//! let _scene = layers.push(ColorLayer::filled(320, 240, Argb::opaque(0, 0, 255)));
+//!
+//! let mut toast = ColorLayer::filled(200, 40, Argb::opaque(255, 255, 255));
+//! toast.x = 60;
+//! toast.y = 180;
+//! toast.z = 1;
+//! toast.opacity = 128;
+//! let toast = layers.push(toast);
+//!
+//! let mut frame = ColorFramebuffer::new();
+//! layers.composite(&mut frame);
+//! assert_eq!(frame.pixel(100, 200), Argb::opaque(128, 128, 255));
+//!
+//! layers.get_mut(toast).unwrap().visible = false;
+//! layers.composite(&mut frame);
+//! assert_eq!(frame.pixel(100, 200), Argb::opaque(0, 0, 255));
+//! ```
+
+use canvas::{Canvas, ColorCanvas};
+use color::Argb;
+use packed::{BlitOp, PackedMonoBitmap};
+
+/// How the color channels of a [ColorLayer](struct.ColorLayer.html) relate to its alpha.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alpha {
+    /// Color channels are independent of alpha, the layout of the color LCD background.
+    Straight,
+    /// Color channels are already multiplied by alpha, the output of most rasterizers.
+    Premultiplied,
+}
+
+/// A BGRA bitmap composited with alpha blending.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorLayer {
+    width: usize,
+    height: usize,
+    alpha: Alpha,
+    data: Vec<u8>,
+    /// Left edge on the target, may be negative.
+    pub x: isize,
+    /// Top edge on the target, may be negative.
+    pub y: isize,
+    /// Stacking order, higher layers are drawn on top. Layers with the same z are drawn in
+    /// the order they were pushed.
+    pub z: i32,
+    /// Opacity applied on top of the per-pixel alpha, 255 is fully opaque.
+    pub opacity: u8,
+    /// Hidden layers are skipped.
+    pub visible: bool,
+}
+
+impl ColorLayer {
+    /// Creates a visible, fully opaque layer at (0, 0) from BGRA data.
+    ///
+    /// Panics:
+    /// - If data's length is not `width * height * 4` bytes.
+    ///
+    pub fn new(width: usize, height: usize, data: Vec<u8>, alpha: Alpha) -> ColorLayer {
+        assert_eq!(data.len(), width * height * 4);
+        ColorLayer {
+            width,
+            height,
+            alpha,
+            data,
+            x: 0,
+            y: 0,
+            z: 0,
+            opacity: 255,
+            visible: true,
+        }
+    }
+
+    /// Creates a layer with straight alpha filled with one color.
+    pub fn filled(width: usize, height: usize, color: Argb) -> ColorLayer {
+        let data = color.to_bgra().iter().cloned().cycle().take(width * height * 4).collect();
+        ColorLayer::new(width, height, data, Alpha::Straight)
+    }
+
+    /// Width in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// How the color channels relate to alpha.
+    pub fn alpha(&self) -> Alpha {
+        self.alpha
+    }
+
+    /// The BGRA pixel data.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// The BGRA pixel data, for rendering into the layer.
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
+    /// A canvas over the layer's pixels. Canvases write straight alpha, so this is only
+    /// meaningful for layers with [Alpha::Straight](enum.Alpha.html).
+    pub fn canvas(&mut self) -> ColorCanvas<'_> {
+        ColorCanvas::with_size(&mut self.data, self.width, self.height)
+    }
+
+    /// Reads a pixel with straight alpha.
+    fn straight_pixel(&self, x: usize, y: usize) -> Argb {
+        let i = (y * self.width + x) * 4;
+        let px = Argb::from_bgra(&self.data[i..i + 4]);
+        match self.alpha {
+            Alpha::Straight => px,
+            Alpha::Premultiplied if px.a == 0 => Argb::new(0, 0, 0, 0),
+            Alpha::Premultiplied => {
+                let demultiply = |c: u8| (c as u32 * 255 / px.a as u32).min(255) as u8;
+                Argb::new(px.a, demultiply(px.r), demultiply(px.g), demultiply(px.b))
+            },
+        }
+    }
+}
+
+/// A packed 1-bit bitmap combined with the target by a bitwise operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonoLayer {
+    /// The layer's pixels.
+    pub bitmap: PackedMonoBitmap,
+    /// How the layer's pixels are combined with the pixels below, only inside the layer's
+    /// bounds. [BlitOp::And](../packed/enum.BlitOp.html) masks what is below.
+    pub op: BlitOp,
+    /// Left edge on the target, may be negative.
+    pub x: isize,
+    /// Top edge on the target, may be negative.
+    pub y: isize,
+    /// Stacking order, higher layers are applied later. Layers with the same z are applied
+    /// in the order they were pushed.
+    pub z: i32,
+    /// Hidden layers are skipped.
+    pub visible: bool,
+}
+
+impl MonoLayer {
+    /// Creates a visible layer at (0, 0).
+    pub fn new(bitmap: PackedMonoBitmap, op: BlitOp) -> MonoLayer {
+        MonoLayer { bitmap, op, x: 0, y: 0, z: 0, visible: true }
+    }
+}
+
+/// Identifies a layer in a [LayerStack](struct.LayerStack.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LayerId(usize);
+
+/// An ordered set of layers.
+#[derive(Debug, Clone)]
+pub struct LayerStack<L> {
+    layers: Vec<(LayerId, L)>,
+    next_id: usize,
+}
+
+impl<L> LayerStack<L> {
+    /// Creates an empty stack.
+    pub fn new() -> LayerStack<L> {
+        LayerStack { layers: Vec::new(), next_id: 0 }
+    }
+
+    /// Adds a layer and returns its id.
+    pub fn push(&mut self, layer: L) -> LayerId {
+        let id = LayerId(self.next_id);
+        self.next_id += 1;
+        self.layers.push((id, layer));
+        id
+    }
+
+    /// Removes a layer and returns it, or `None` if it was already removed.
+    pub fn remove(&mut self, id: LayerId) -> Option<L> {
+        let index = self.layers.iter().position(|&(i, _)| i == id)?;
+        Some(self.layers.remove(index).1)
+    }
+
+    /// Returns a layer, or `None` if it was removed.
+    pub fn get(&self, id: LayerId) -> Option<&L> {
+        self.layers.iter().find(|&&(i, _)| i == id).map(|(_, l)| l)
+    }
+
+    /// Returns a layer for changing its position, order, visibility or pixels.
+    pub fn get_mut(&mut self, id: LayerId) -> Option<&mut L> {
+        self.layers.iter_mut().find(|&&mut (i, _)| i == id).map(|(_, l)| l)
+    }
+
+    /// Number of layers, including hidden ones.
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Returns `true` if the stack has no layers.
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Layers in the order they are composited, bottom first.
+    fn sorted<F: Fn(&L) -> i32>(&self, z: F) -> Vec<&L> {
+        let mut layers: Vec<&L> = self.layers.iter().map(|(_, l)| l).collect();
+        layers.sort_by_key(|l| z(l));
+        layers
+    }
+}
+
+impl<L> Default for LayerStack<L> {
+    fn default() -> LayerStack<L> {
+        LayerStack::new()
+    }
+}
+
+impl LayerStack<ColorLayer> {
+    /// Blends all visible layers onto a color canvas, bottom to top.
+    ///
+    /// The canvas keeps its contents where no layer covers it, clear it first to start
+    /// from a blank frame.
+    pub fn composite<C: Canvas<Pixel = Argb>>(&self, canvas: &mut C) {
+        for layer in self.sorted(|l| l.z) {
+            if !layer.visible || layer.opacity == 0 {
+                continue;
+            }
+            for ly in 0..layer.height {
+                for lx in 0..layer.width {
+                    let px = layer.straight_pixel(lx, ly);
+                    if px.a != 0 {
+                        canvas.put_blended(layer.x + lx as isize, layer.y + ly as isize, px,
+                            layer.opacity);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl LayerStack<MonoLayer> {
+    /// Applies all visible layers to a monochrome canvas, bottom to top.
+    ///
+    /// The canvas keeps its contents where no layer covers it, clear it first to start
+    /// from a blank frame. Canvases store a byte per pixel, so layers are applied one
+    /// pixel at a time, [composite_packed](#method.composite_packed) is faster.
+    pub fn composite<C: Canvas<Pixel = bool>>(&self, canvas: &mut C) {
+        let clip = canvas.clip_rect();
+        for layer in self.sorted(|l| l.z) {
+            if !layer.visible {
+                continue;
+            }
+            for ly in 0..layer.bitmap.height() {
+                let y = layer.y + ly as isize;
+                for lx in 0..layer.bitmap.width() {
+                    let x = layer.x + lx as isize;
+                    if x < 0 || y < 0 || !clip.contains(x as usize, y as usize) {
+                        continue;
+                    }
+                    let (x, y) = (x as usize, y as usize);
+                    // A single pixel in the lowest bit.
+                    let on = layer.op.apply(canvas.pixel(x, y) as u8,
+                        layer.bitmap.get(lx, ly) as u8) & 1 != 0;
+                    canvas.set_pixel(x, y, on);
+                }
+            }
+        }
+    }
+
+    /// Applies all visible layers to a packed bitmap, bottom to top, 8 pixels at a time,
+    /// see [PackedMonoBitmap::blit](../packed/struct.PackedMonoBitmap.html#method.blit).
+    ///
+    /// The bitmap keeps its contents where no layer covers it.
+    pub fn composite_packed(&self, target: &mut PackedMonoBitmap) {
+        for layer in self.sorted(|l| l.z) {
+            if layer.visible {
+                target.blit(&layer.bitmap, layer.x, layer.y, layer.op);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use canvas::MonoCanvas;
+    use packed::{BlitOp, PackedMonoBitmap};
+    use super::{LayerStack, MonoLayer};
+
+    #[test]
+    fn packed_composite_matches_canvas_composite() {
+        let mut stack = LayerStack::new();
+        let ops = [BlitOp::Or, BlitOp::Xor, BlitOp::Clear, BlitOp::And, BlitOp::Copy];
+        for (i, &op) in ops.iter().enumerate() {
+            let mut bitmap = PackedMonoBitmap::new(11 + i, 7);
+            for y in 0..7 {
+                for x in 0..11 + i {
+                    bitmap.set(x, y, (x + y * 3 + i) % 4 < 2);
+                }
+            }
+            let mut layer = MonoLayer::new(bitmap, op);
+            layer.x = i as isize * 5 - 4;
+            layer.y = i as isize * 2 - 3;
+            layer.z = -(i as i32);
+            layer.visible = i != 3;
+            stack.push(layer);
+        }
+
+        let mut bytes = vec![0u8; 24 * 12];
+        stack.composite(&mut MonoCanvas::with_size(&mut bytes, 24, 12));
+        let mut packed = PackedMonoBitmap::new(24, 12);
+        stack.composite_packed(&mut packed);
+        assert_eq!(packed, PackedMonoBitmap::from_luma(24, 12, &bytes));
+        assert!(packed.count_ones() > 0);
+    }
+}
@@ -86,6 +86,45 @@ pub use sys::{
 
 use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
 use std::os::raw::c_int;
+use std::time::Instant;
+
+pub mod asset;
+pub mod canvas;
+pub mod color;
+pub mod dither;
+pub mod draw;
+#[cfg(feature = "embedded-graphics")]
+pub mod embedded;
+pub mod font;
+pub mod frame;
+pub mod framebuffer;
+pub mod icon;
+pub mod layer;
+pub mod markup;
+pub mod marquee;
+pub mod packed;
+pub mod resample;
+#[cfg(feature = "svg")]
+pub mod svg;
+#[cfg(feature = "truetype")]
+pub mod truetype;
+#[cfg(feature = "unicode")]
+pub mod unicode;
+#[cfg(feature = "vector")]
+pub mod vector;
+mod state;
+pub mod swap;
+pub mod table;
+pub mod text;
+
+pub use state::UploadStats;
+use color::Rgb;
+use framebuffer::{ColorFramebuffer, MonoFramebuffer};
+use state::ScreenState;
+use swap::SwapChain;
+use font::Align;
+use marquee::{Marquee, Scroller};
+use text::{LineCapacity, TextLine};
 
 static INITIALIZED: AtomicBool = ATOMIC_BOOL_INIT;
 
@@ -93,17 +132,30 @@ static INITIALIZED: AtomicBool = ATOMIC_BOOL_INIT;
 ///
 /// Initialize at start of your program. Can Be initialized with color support,
 /// monochrome support and both. Will automatically disconnect when the Lcd is dropped.
+///
+/// The driver remembers the backgrounds and text it sent, which can be read back with
+/// getters like [color_text](#method.color_text), and skips calls into the SDK that would
+/// not change anything, see [upload_stats](#method.upload_stats).
 #[derive(Debug)]
 pub struct Driver {
     type_flags: sys::LcdType,
     lib: sys::Library,
+    state: ScreenState,
+    stats: UploadStats,
+    line_capacity: LineCapacity,
+    mono_buffers: Option<SwapChain<MonoFramebuffer>>,
+    color_buffers: Option<SwapChain<ColorFramebuffer>>,
+    marquees: Vec<Scroller>,
 }
 
 /// Runtime LCD error
 ///
 /// The underling Logitech LCD/GamePanel SDK does unfortunately not return any info on error.
 /// We therefore only able report what function failed, but not why.
+///
+/// New variants may be added in minor releases, so matches need a wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// A logitech LCD is not connected to the system.
     NotConnected,
@@ -119,10 +171,27 @@ pub enum Error {
     ColorTitle,
     /// FFI call to LogiLcdColorSetText() in LogitechLcd.dll has failed.
     ColorText,
+    /// FFI call to LogiLcdMonoSetBackgroundUDK() in LogitechLcd.dll has failed.
+    MonoBackgroundPartial,
+    /// FFI call to LogiLcdColorSetBackgroundUDK() in LogitechLcd.dll has failed.
+    ColorBackgroundPartial,
+    /// FFI call to LogiLcdMonoResetBackgroundUDK() or LogiLcdColorResetBackgroundUDK()
+    /// in LogitechLcd.dll has failed.
+    ResetBackground,
     /// Unexpected NULL character
     NullCharacter,
     /// Failed to load LogitechLcd.dll.
     LoadLibrary(std::io::Error),
+    /// Failed to read or write an asset.
+    Io(std::io::Error),
+    /// Malformed or unsupported asset data.
+    Decode(&'static str),
+    /// A [Frame](frame/struct.Frame.html) failed validation.
+    InvalidFrame(&'static str),
+    /// Malformed color notation or unknown color name.
+    ParseColor(&'static str),
+    /// Malformed [markup](markup/index.html).
+    Markup(&'static str),
 }
 
 impl std::error::Error for Error {
@@ -135,14 +204,23 @@ impl std::error::Error for Error {
             Error::ColorBackground => "FFI call to LogiLcdColorSetTitle() in LogitechLcd.dll has failed.",
             Error::ColorTitle      => "FFI call to LogiLcdColorSetTitle() in LogitechLcd.dll has failed.",
             Error::ColorText       => "FFI call to LogiLcdColorSetText() in LogitechLcd.dll has failed.",
+            Error::MonoBackgroundPartial => "FFI call to LogiLcdMonoSetBackgroundUDK() in LogitechLcd.dll has failed.",
+            Error::ColorBackgroundPartial => "FFI call to LogiLcdColorSetBackgroundUDK() in LogitechLcd.dll has failed.",
+            Error::ResetBackground => "FFI call to LogiLcdMonoResetBackgroundUDK() or LogiLcdColorResetBackgroundUDK() in LogitechLcd.dll has failed.",
             Error::NullCharacter   => "Unexpected NULL character.",
             Error::LoadLibrary(_)  => "Failed to load LogitechLcd.dll",
+            Error::Io(_)           => "Failed to read or write asset.",
+            Error::Decode(msg)     => msg,
+            Error::InvalidFrame(msg) => msg,
+            Error::ParseColor(msg) => msg,
+            Error::Markup(msg) => msg,
         }
     }
 
     fn cause(&self) -> Option<&std::error::Error> {
         match *self {
             Error::LoadLibrary(ref e) => Some(e),
+            Error::Io(ref e) => Some(e),
             _ => None,
         }
     }
@@ -191,6 +269,12 @@ impl Driver {
                         true => Ok(Driver {
                             type_flags: type_flags,
                             lib: lib,
+                            state: ScreenState::default(),
+                            stats: UploadStats::default(),
+                            line_capacity: LineCapacity::default(),
+                            mono_buffers: None,
+                            color_buffers: None,
+                            marquees: Vec::new(),
                         }),
                         false => Err(Error::NotConnected),
                     }
@@ -256,8 +340,10 @@ impl Driver {
     /// Updates the lcd display.
     ///
     /// You have to call this function every frame of your main loop, to keep the lcd updated.
+    /// Marquees are advanced before the display is updated, see [marquee](marquee/index.html).
     ///
     pub fn update(&mut self) {
+        self.advance_marquees();
         unsafe {
             (self.lib.LogiLcdUpdate)();
         }
@@ -290,12 +376,90 @@ impl Driver {
         assert!(!(self.type_flags | sys::LcdType::MONO).is_empty());
         assert_eq!(mono_bitmap.len(), MONO_WIDTH * MONO_HEIGHT);
 
-        unsafe {
+        if state::is_current(&self.state.mono_background, mono_bitmap) {
+            self.stats.backgrounds_skipped += 1;
+            return Ok(());
+        }
+
+        let ret = unsafe {
             match (self.lib.LogiLcdMonoSetBackground)(mono_bitmap.as_ptr()) {
                 true => Ok(()),
                 false => Err(Error::MonoBackground),
             }
+        };
+
+        match ret {
+            Ok(()) => state::store(&mut self.state.mono_background, mono_bitmap),
+            Err(_) => self.state.mono_background = None,
+        }
+        self.state.mono_partial = None;
+        self.stats.backgrounds_sent += 1;
+        ret
+    }
+
+    /// Sets a packed 1-bit bitmap as background for the monochrome lcd device.
+    ///
+    /// The bitmap is expanded to the 8 bits per pixel format expected by the SDK on the
+    /// stack, without allocating. See [set_mono_background](#method.set_mono_background).
+    ///
+    /// Panics:
+    /// - If bitmap is not 160x43 pixels.
+    /// - If Lcd was initialized without mono support.
+    ///
+    pub fn set_packed_mono_background(&mut self, bitmap: &packed::PackedMonoBitmap)
+        -> Result<(), Error>
+    {
+        assert!(bitmap.width() == MONO_WIDTH && bitmap.height() == MONO_HEIGHT);
+
+        let mut mono_bitmap = [0u8; MONO_WIDTH * MONO_HEIGHT];
+        bitmap.write_luma(&mut mono_bitmap);
+        self.set_mono_background(&mono_bitmap)
+    }
+
+    /// Writes part of the monochrome background.
+    ///
+    /// The SDK keeps a write position into the background. Each call copies
+    /// `partial_bitmap` to that position and advances it, so a background can be sent
+    /// in several chunks, or only its first rows can be replaced. The position is moved
+    /// back to the top left corner by [reset_background](#method.reset_background).
+    ///
+    /// The SDK headers only declare these functions, they don't document the write
+    /// position. What is described here is how they are expected to behave, it is not
+    /// guaranteed. In particular it is unknown where the position is after
+    /// initialization or after a full background upload, so call
+    /// [reset_background](#method.reset_background) before a sequence of partial writes.
+    ///
+    /// Parameters:
+    /// - partial_bitmap: Consecutive bytes of the background, in the layout of
+    ///   [set_mono_background](#method.set_mono_background).
+    ///
+    /// Panics:
+    /// - If partial_bitmap's length is larger than 160x43 bytes.
+    /// - If Lcd was initialized without mono support.
+    ///
+    pub fn set_mono_background_partial(&mut self, partial_bitmap: &[u8]) -> Result<(), Error> {
+        assert!(!(self.type_flags | sys::LcdType::MONO).is_empty());
+        assert!(partial_bitmap.len() <= MONO_WIDTH * MONO_HEIGHT);
+
+        let ret = unsafe {
+            match (self.lib.LogiLcdMonoSetBackgroundUDK)(partial_bitmap.as_ptr(),
+                partial_bitmap.len() as c_int)
+            {
+                0 => Err(Error::MonoBackgroundPartial),
+                _ => Ok(()),
+            }
+        };
+
+        match ret {
+            Ok(()) => state::store_partial(&mut self.state.mono_background,
+                &mut self.state.mono_partial, partial_bitmap, MONO_WIDTH * MONO_HEIGHT),
+            Err(_) => {
+                self.state.mono_background = None;
+                self.state.mono_partial = None;
+            }
         }
+        self.stats.backgrounds_sent += 1;
+        ret
     }
 
     /// Sets the specified text in the requested line on the monochrome lcd device.
@@ -310,17 +474,63 @@ impl Driver {
     /// - If Lcd was initialized without mono support.
     ///
     pub fn set_mono_text(&mut self, line_number: usize, text: &str) -> Result<(), Error> {
+        self.stop_marquee(TextLine::Mono(line_number));
+        self.send_mono_text(line_number, text)
+    }
+
+    fn send_mono_text(&mut self, line_number: usize, text: &str) -> Result<(), Error> {
         assert!(!(self.type_flags | sys::LcdType::MONO).is_empty());
 
         let ws = str_to_wchar_checked(text)?;
         assert!(line_number < 4);
 
-        unsafe {
+        if self.state.mono_text[line_number].as_ref().is_some_and(|t| t == text) {
+            self.stats.texts_skipped += 1;
+            return Ok(());
+        }
+
+        let ret = unsafe {
             match (self.lib.LogiLcdMonoSetText)(line_number as c_int, ws.as_ptr()) {
                 true => Ok(()),
                 false => Err(Error::MonoText),
             }
+        };
+
+        self.state.mono_text[line_number] = ret.as_ref().ok().map(|_| text.to_owned());
+        self.stats.texts_sent += 1;
+        ret
+    }
+
+    /// Sets text on a monochrome line, truncated with an ellipsis if it is longer than the
+    /// [line capacity](#method.line_capacity) and padded to align it.
+    ///
+    /// Panics:
+    /// - If line_number larger than or equal to 4.
+    /// - If Lcd was initialized without mono support.
+    ///
+    pub fn set_mono_text_aligned(&mut self, line_number: usize, text: &str, align: Align)
+        -> Result<(), Error>
+    {
+        self.set_mono_text(line_number, &text::fit(text, self.line_capacity.mono_line, align))
+    }
+
+    /// Word-wraps text across the 4 monochrome lines at the
+    /// [line capacity](#method.line_capacity), see [text::wrap](text/fn.wrap.html).
+    /// Lines not needed by the text are cleared.
+    ///
+    /// Return value:
+    /// The part of the text that didn't fit, starting at the first line that was not
+    /// shown, or `None` if everything fit. Pass it to the next call to paginate.
+    ///
+    /// Panics:
+    /// - If Lcd was initialized without mono support.
+    ///
+    pub fn set_mono_paragraph<'t>(&mut self, text: &'t str) -> Result<Option<&'t str>, Error> {
+        let lines = text::wrap(text, self.line_capacity.mono_line);
+        for line_number in 0..4 {
+            self.set_mono_text(line_number, lines.get(line_number).cloned().unwrap_or(""))?;
         }
+        Ok(lines.get(4).map(|line| text::remainder(text, line)))
     }
 
     /// Sets the specified image as background for the color lcd device connected.
@@ -337,12 +547,97 @@ impl Driver {
         assert!(!(self.type_flags | sys::LcdType::COLOR).is_empty());
         assert_eq!(color_bitmap.len(), COLOR_WIDTH * COLOR_HEIGHT * 4);
 
-        unsafe {
+        if state::is_current(&self.state.color_background, color_bitmap) {
+            self.stats.backgrounds_skipped += 1;
+            return Ok(());
+        }
+
+        let ret = unsafe {
             match (self.lib.LogiLcdColorSetBackground)(color_bitmap.as_ptr()) {
                 true => Ok(()),
                 false => Err(Error::ColorBackground),
             }
+        };
+
+        match ret {
+            Ok(()) => state::store(&mut self.state.color_background, color_bitmap),
+            Err(_) => self.state.color_background = None,
+        }
+        self.state.color_partial = None;
+        self.stats.backgrounds_sent += 1;
+        ret
+    }
+
+    /// Writes part of the color background.
+    ///
+    /// Works like [set_mono_background_partial](#method.set_mono_background_partial),
+    /// with data in the layout of [set_color_background](#method.set_color_background).
+    ///
+    /// Panics:
+    /// - If partial_bitmap's length is larger than 320x240x4 bytes.
+    /// - If Lcd was initialized without color support.
+    ///
+    pub fn set_color_background_partial(&mut self, partial_bitmap: &[u8])
+        -> Result<(), Error>
+    {
+        assert!(!(self.type_flags | sys::LcdType::COLOR).is_empty());
+        assert!(partial_bitmap.len() <= COLOR_WIDTH * COLOR_HEIGHT * 4);
+
+        let ret = unsafe {
+            match (self.lib.LogiLcdColorSetBackgroundUDK)(partial_bitmap.as_ptr(),
+                partial_bitmap.len() as c_int)
+            {
+                0 => Err(Error::ColorBackgroundPartial),
+                _ => Ok(()),
+            }
+        };
+
+        match ret {
+            Ok(()) => state::store_partial(&mut self.state.color_background,
+                &mut self.state.color_partial, partial_bitmap, COLOR_WIDTH * COLOR_HEIGHT * 4),
+            Err(_) => {
+                self.state.color_background = None;
+                self.state.color_partial = None;
+            }
         }
+        self.stats.backgrounds_sent += 1;
+        ret
+    }
+
+    /// Moves the write position of the partial background functions back to the top left
+    /// corner, for every lcd type the driver was initialized with.
+    pub fn reset_background(&mut self) -> Result<(), Error> {
+        if self.type_flags.contains(sys::LcdType::MONO) {
+            self.reset_mono_background()?;
+        }
+        if self.type_flags.contains(sys::LcdType::COLOR) {
+            self.reset_color_background()?;
+        }
+        Ok(())
+    }
+
+    /// Moves the write position of the monochrome partial background function back to the
+    /// top left corner.
+    pub(crate) fn reset_mono_background(&mut self) -> Result<(), Error> {
+        assert!(self.type_flags.contains(sys::LcdType::MONO));
+        if unsafe { (self.lib.LogiLcdMonoResetBackgroundUDK)() } == 0 {
+            self.state.mono_partial = None;
+            return Err(Error::ResetBackground);
+        }
+        self.state.mono_partial = Some(0);
+        Ok(())
+    }
+
+    /// Moves the write position of the color partial background function back to the top
+    /// left corner.
+    pub(crate) fn reset_color_background(&mut self) -> Result<(), Error> {
+        assert!(self.type_flags.contains(sys::LcdType::COLOR));
+        if unsafe { (self.lib.LogiLcdColorResetBackgroundUDK)() } == 0 {
+            self.state.color_partial = None;
+            return Err(Error::ResetBackground);
+        }
+        self.state.color_partial = Some(0);
+        Ok(())
     }
 
     /// Sets the specified text in the first line on the color lcd device connected.
@@ -359,18 +654,35 @@ impl Driver {
     ///
     pub fn set_color_title(&mut self, text: &str, red: u8, green: u8, blue: u8)
         -> Result<(), Error>
+    {
+        self.stop_marquee(TextLine::ColorTitle);
+        self.send_color_title(text, red, green, blue)
+    }
+
+    fn send_color_title(&mut self, text: &str, red: u8, green: u8, blue: u8)
+        -> Result<(), Error>
     {
         assert!(!(self.type_flags | sys::LcdType::COLOR).is_empty());
         let ws = str_to_wchar_checked(text)?;
 
-        unsafe {
+        let color = Rgb::new(red, green, blue);
+        if self.state.color_title.as_ref().is_some_and(|t| t.0 == text && t.1 == color) {
+            self.stats.texts_skipped += 1;
+            return Ok(());
+        }
+
+        let ret = unsafe {
             match (self.lib.LogiLcdColorSetTitle)(ws.as_ptr(), red as c_int,
                 green as c_int, blue as c_int)
             {
                 true  => Ok(()),
                 false => Err(Error::ColorTitle),
             }
-        }
+        };
+
+        self.state.color_title = ret.as_ref().ok().map(|_| (text.to_owned(), color));
+        self.stats.texts_sent += 1;
+        ret
     }
 
     /// Sets the specified text in the requested line on the color lcd device connected.
@@ -388,20 +700,326 @@ impl Driver {
     ///
     pub fn set_color_text(&mut self, line_number: usize, text: &str,
         red: u8, green: u8, blue: u8) -> Result<(), Error>
+    {
+        self.stop_marquee(TextLine::Color(line_number));
+        self.send_color_text(line_number, text, red, green, blue)
+    }
+
+    fn send_color_text(&mut self, line_number: usize, text: &str,
+        red: u8, green: u8, blue: u8) -> Result<(), Error>
     {
         assert!(!(self.type_flags | sys::LcdType::COLOR).is_empty());
 
         let ws = str_to_wchar_checked(text)?;
         assert!(line_number < 8);
 
-        unsafe {
+        let color = Rgb::new(red, green, blue);
+        let current = &self.state.color_text[line_number];
+        if current.as_ref().is_some_and(|t| t.0 == text && t.1 == color) {
+            self.stats.texts_skipped += 1;
+            return Ok(());
+        }
+
+        let ret = unsafe {
             match (self.lib.LogiLcdColorSetText)(line_number as c_int,
                 ws.as_ptr(), red as c_int, green as c_int, blue as c_int)
             {
                 true => Ok(()),
                 false => Err(Error::ColorText),
             }
+        };
+
+        self.state.color_text[line_number] = ret.as_ref().ok().map(|_| (text.to_owned(), color));
+        self.stats.texts_sent += 1;
+        ret
+    }
+
+    /// Sets the color title like [set_color_title](#method.set_color_title), with the color
+    /// given as an [Rgb](color/struct.Rgb.html).
+    ///
+    /// Panics:
+    /// - If Lcd was initialized without color support.
+    ///
+    pub fn set_color_title_rgb(&mut self, text: &str, color: Rgb) -> Result<(), Error> {
+        self.set_color_title(text, color.r, color.g, color.b)
+    }
+
+    /// Sets a color text line like [set_color_text](#method.set_color_text), with the color
+    /// given as an [Rgb](color/struct.Rgb.html).
+    ///
+    /// Panics:
+    /// - If line_number larger than or equal to 8.
+    /// - If Lcd was initialized without color support.
+    ///
+    pub fn set_color_text_rgb(&mut self, line_number: usize, text: &str, color: Rgb)
+        -> Result<(), Error>
+    {
+        self.set_color_text(line_number, text, color.r, color.g, color.b)
+    }
+
+    /// Sets text on a color line, truncated with an ellipsis if it is longer than the
+    /// [line capacity](#method.line_capacity) and padded to align it.
+    ///
+    /// Panics:
+    /// - If line_number larger than or equal to 8.
+    /// - If Lcd was initialized without color support.
+    ///
+    pub fn set_color_text_aligned(&mut self, line_number: usize, text: &str, align: Align,
+        red: u8, green: u8, blue: u8) -> Result<(), Error>
+    {
+        let text = text::fit(text, self.line_capacity.color_line, align);
+        self.set_color_text(line_number, &text, red, green, blue)
+    }
+
+    /// Word-wraps text across the 8 color lines in one color at the
+    /// [line capacity](#method.line_capacity), see [text::wrap](text/fn.wrap.html). Lines
+    /// not needed by the text are cleared.
+    ///
+    /// Return value:
+    /// The part of the text that didn't fit, starting at the first line that was not
+    /// shown, or `None` if everything fit. Pass it to the next call to paginate.
+    ///
+    /// Panics:
+    /// - If Lcd was initialized without color support.
+    ///
+    pub fn set_color_paragraph<'t>(&mut self, text: &'t str, color: Rgb)
+        -> Result<Option<&'t str>, Error>
+    {
+        let lines = text::wrap(text, self.line_capacity.color_line);
+        for line_number in 0..8 {
+            let line = lines.get(line_number).cloned().unwrap_or("");
+            self.set_color_text(line_number, line, color.r, color.g, color.b)?;
+        }
+        Ok(lines.get(8).map(|line| text::remainder(text, line)))
+    }
+
+    /// The text last set on a monochrome line, `None` if it was never set or the last
+    /// attempt failed.
+    ///
+    /// Panics:
+    /// - If line_number larger than or equal to 4.
+    ///
+    pub fn mono_text(&self, line_number: usize) -> Option<&str> {
+        self.state.mono_text[line_number].as_ref().map(|t| &t[..])
+    }
+
+    /// The title and title color last set on the color lcd, `None` if it was never set or
+    /// the last attempt failed.
+    pub fn color_title(&self) -> Option<(&str, Rgb)> {
+        self.state.color_title.as_ref().map(|t| (&t.0[..], t.1))
+    }
+
+    /// The text and color last set on a color line, `None` if it was never set or the last
+    /// attempt failed.
+    ///
+    /// Panics:
+    /// - If line_number larger than or equal to 8.
+    ///
+    pub fn color_text(&self, line_number: usize) -> Option<(&str, Rgb)> {
+        self.state.color_text[line_number].as_ref().map(|t| (&t.0[..], t.1))
+    }
+
+    /// The monochrome background as last sent, including partial writes. `None` if it was
+    /// never set, the last attempt failed, or a partial write went past its end or was made
+    /// without a [reset_background](#method.reset_background) before it.
+    pub fn mono_background(&self) -> Option<&[u8]> {
+        self.state.mono_background.as_ref().map(|b| &b[..])
+    }
+
+    /// The color background as last sent, including partial writes. `None` if it was
+    /// never set, the last attempt failed, or a partial write went past its end or was made
+    /// without a [reset_background](#method.reset_background) before it.
+    pub fn color_background(&self) -> Option<&[u8]> {
+        self.state.color_background.as_ref().map(|b| &b[..])
+    }
+
+    /// Scrolls text through a monochrome line on every [update](#method.update), see
+    /// [marquee](marquee/index.html). Text that fits the line is shown as is.
+    ///
+    /// Parameters:
+    /// - line_number: The line, from 0 to 3.
+    /// - text: The full text to scroll.
+    /// - marquee: Speed, pause and mode of the marquee.
+    ///
+    /// Panics:
+    /// - If line_number larger than or equal to 4.
+    /// - If Lcd was initialized without mono support.
+    ///
+    pub fn set_mono_marquee(&mut self, line_number: usize, text: &str, marquee: Marquee)
+        -> Result<(), Error>
+    {
+        assert!(line_number < 4);
+        self.start_marquee(TextLine::Mono(line_number), text, Rgb::new(255, 255, 255), marquee)
+    }
+
+    /// Scrolls text through the color title on every [update](#method.update), see
+    /// [marquee](marquee/index.html). Text that fits the title is shown as is.
+    ///
+    /// Panics:
+    /// - If Lcd was initialized without color support.
+    ///
+    pub fn set_color_title_marquee(&mut self, text: &str, color: Rgb, marquee: Marquee)
+        -> Result<(), Error>
+    {
+        self.start_marquee(TextLine::ColorTitle, text, color, marquee)
+    }
+
+    /// Scrolls text through a color line on every [update](#method.update), see
+    /// [marquee](marquee/index.html). Text that fits the line is shown as is.
+    ///
+    /// Panics:
+    /// - If line_number larger than or equal to 8.
+    /// - If Lcd was initialized without color support.
+    ///
+    pub fn set_color_marquee(&mut self, line_number: usize, text: &str, color: Rgb,
+        marquee: Marquee) -> Result<(), Error>
+    {
+        assert!(line_number < 8);
+        self.start_marquee(TextLine::Color(line_number), text, color, marquee)
+    }
+
+    /// Stops the marquee of a line, leaving the part of the text currently shown on it.
+    /// Does nothing if the line has no marquee.
+    pub fn stop_marquee(&mut self, line: TextLine) {
+        self.marquees.retain(|m| m.line() != line);
+    }
+
+    /// Returns `true` if the line has a marquee.
+    pub fn has_marquee(&self, line: TextLine) -> bool {
+        self.marquees.iter().any(|m| m.line() == line)
+    }
+
+    fn start_marquee(&mut self, line: TextLine, text: &str, color: Rgb, marquee: Marquee)
+        -> Result<(), Error>
+    {
+        self.stop_marquee(line);
+        let width = self.line_capacity.chars(line);
+        let scroller = Scroller::new(line, width, text, color, marquee, Instant::now());
+        self.send_line(line, &scroller.window(), color)?;
+        self.marquees.push(scroller);
+        Ok(())
+    }
+
+    /// Sends the new window of every marquee that moved. A line that fails to send is tried
+    /// again on its next step.
+    fn advance_marquees(&mut self) {
+        if self.marquees.is_empty() {
+            return;
+        }
+        let now = Instant::now();
+        let mut marquees = std::mem::take(&mut self.marquees);
+        for scroller in marquees.iter_mut() {
+            if scroller.advance(now) {
+                let _ = self.send_line(scroller.line(), &scroller.window(), scroller.color());
+            }
         }
+        self.marquees = marquees;
+    }
+
+    fn send_line(&mut self, line: TextLine, text: &str, color: Rgb) -> Result<(), Error> {
+        match line {
+            TextLine::Mono(line_number) => self.send_mono_text(line_number, text),
+            TextLine::ColorTitle => self.send_color_title(text, color.r, color.g, color.b),
+            TextLine::Color(line_number) => {
+                self.send_color_text(line_number, text, color.r, color.g, color.b)
+            },
+        }
+    }
+
+    /// Clears the text lines, the title and the background of every lcd type the driver
+    /// was initialized with. Backgrounds are set to all zeros.
+    pub fn clear_all(&mut self) -> Result<(), Error> {
+        if self.type_flags.contains(sys::LcdType::MONO) {
+            self.set_mono_background(&[0; MONO_WIDTH * MONO_HEIGHT])?;
+            for line_number in 0..4 {
+                self.set_mono_text(line_number, "")?;
+            }
+        }
+        if self.type_flags.contains(sys::LcdType::COLOR) {
+            self.set_color_background(&vec![0; COLOR_WIDTH * COLOR_HEIGHT * 4])?;
+            self.set_color_title("", 255, 255, 255)?;
+            for line_number in 0..8 {
+                self.set_color_text(line_number, "", 255, 255, 255)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The monochrome back buffer of the driver's swap chain, the next frame to
+    /// [present](#method.present). Both buffers are allocated on first use.
+    ///
+    /// Panics:
+    /// - If Lcd was initialized without mono support.
+    ///
+    pub fn mono_back_buffer(&mut self) -> &mut MonoFramebuffer {
+        assert!(self.type_flags.contains(sys::LcdType::MONO));
+        let buffers = self.mono_buffers.get_or_insert_with(|| {
+            SwapChain::new(MonoFramebuffer::new(), MonoFramebuffer::new())
+        });
+        buffers.back_mut()
+    }
+
+    /// The color back buffer of the driver's swap chain, the next frame to
+    /// [present](#method.present). Both buffers are allocated on first use.
+    ///
+    /// Panics:
+    /// - If Lcd was initialized without color support.
+    ///
+    pub fn color_back_buffer(&mut self) -> &mut ColorFramebuffer {
+        assert!(self.type_flags.contains(sys::LcdType::COLOR));
+        let buffers = self.color_buffers.get_or_insert_with(|| {
+            SwapChain::new(ColorFramebuffer::new(), ColorFramebuffer::new())
+        });
+        buffers.back_mut()
+    }
+
+    /// Uploads the back buffers that were used since the last present, swaps them with
+    /// their front buffers and updates the lcd display. A swap chain whose back buffer was
+    /// not borrowed through [mono_back_buffer](#method.mono_back_buffer) or
+    /// [color_back_buffer](#method.color_back_buffer) is left as is, see
+    /// [SwapChain::present](swap/struct.SwapChain.html#method.present).
+    ///
+    /// If an upload fails its buffers are not swapped and the display is not updated.
+    ///
+    /// Presenting doesn't allocate. A back buffer identical to the current background is
+    /// not sent again, see [upload_stats](#method.upload_stats).
+    pub fn present(&mut self) -> Result<(), Error> {
+        if let Some(mut buffers) = self.mono_buffers.take() {
+            let ret = buffers.present(|back| back.flush(self));
+            self.mono_buffers = Some(buffers);
+            ret?;
+        }
+        if let Some(mut buffers) = self.color_buffers.take() {
+            let ret = buffers.present(|back| back.flush(self));
+            self.color_buffers = Some(buffers);
+            ret?;
+        }
+        self.update();
+        Ok(())
+    }
+
+    /// Number of uploads sent to the SDK and skipped because they would not have changed
+    /// anything, since the driver was initialized or
+    /// [reset_upload_stats](#method.reset_upload_stats) was called.
+    pub fn upload_stats(&self) -> UploadStats {
+        self.stats
+    }
+
+    /// Sets all counters of [upload_stats](#method.upload_stats) to zero.
+    pub fn reset_upload_stats(&mut self) {
+        self.stats = UploadStats::default();
+    }
+
+    /// Number of characters the text helpers fit on each kind of text line, estimates
+    /// unless set with [set_line_capacity](#method.set_line_capacity).
+    pub fn line_capacity(&self) -> LineCapacity {
+        self.line_capacity
+    }
+
+    /// Sets the number of characters the text helpers fit on each kind of text line, see
+    /// [LineCapacity](text/struct.LineCapacity.html).
+    pub fn set_line_capacity(&mut self, capacity: LineCapacity) {
+        self.line_capacity = capacity;
     }
 }
 
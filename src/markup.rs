@@ -0,0 +1,349 @@
+//! Rich text rendered into the color background.
+//!
+//! The SDK draws each text line in a single color. A [Markup](struct.Markup.html) renders
+//! text with colored and bold spans and inline icons into a background bitmap instead,
+//! at roughly the positions of the SDK's lines, so it can stand in for
+//! [set_color_text](../struct.Driver.html#method.set_color_text) when a line needs more
+//! than one style.
+//!
+//! Markup is plain text with tags in square brackets:
+//!
+//! - `[red]`, `[#ff8000]`: Text in a CSS named or hex color, see
+//!   [Rgb](../color/struct.Rgb.html).
+//! - `[b]`: Bold text.
+//! - `[icon:name]`: An icon registered with [icon](struct.Markup.html#method.icon), drawn
+//!   in the current color.
+//! - `[/]`: Ends the innermost open tag.
+//! - `[[`: A literal `[`.
+//!
+//! ```
+//! use logitech_lcd::canvas::ColorCanvas;
+//! use logitech_lcd::color::Rgb;
+//! use logitech_lcd::font::{Align, FONT_8X13};
+//! use logitech_lcd::markup::Markup;
+//! use logitech_lcd::packed::PackedMonoBitmap;
+//! use logitech_lcd::text::TextLine;
+//! use logitech_lcd::{COLOR_WIDTH, COLOR_HEIGHT};
+//!
+//! let heart = PackedMonoBitmap::from_bytes(5, 4, vec![0x0a, 0x1f, 0x0e, 0x04]);
+//! let markup = Markup::new(FONT_8X13, Rgb::new(255, 255, 255)).icon("heart", heart);
+//!
+//! let mut background = vec![0u8; COLOR_WIDTH * COLOR_HEIGHT * 4];
+//! let width = markup.draw_line(&mut ColorCanvas::new(&mut background), TextLine::Color(0),
+//!     "[red][icon:heart] HP[/] 100 [b]bold[/]", Align::Left).unwrap();
+//! assert_eq!(width, markup.measure("[red][icon:heart] HP[/] 100 [b]bold[/]").unwrap());
+//! ```
+
+use std::collections::HashMap;
+
+use canvas::Canvas;
+use color::{Argb, Rgb};
+use font::{Align, BitmapFont};
+use icon::{Icon, IconSize};
+use packed::PackedMonoBitmap;
+use text::{LineRects, TextLine};
+use Error;
+
+/// Gap in pixels between an icon and the text after it.
+const ICON_SPACING: usize = 1;
+
+/// The style of a span of text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Style {
+    color: Rgb,
+    bold: bool,
+}
+
+/// A piece of parsed markup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Span<'a> {
+    Text(String, Style),
+    Icon(&'a str, Style),
+}
+
+/// A font, a default color and a set of named icons for rendering markup.
+#[derive(Debug, Clone)]
+pub struct Markup {
+    font: BitmapFont,
+    color: Rgb,
+    icons: HashMap<String, PackedMonoBitmap>,
+    rects: LineRects,
+}
+
+impl Markup {
+    /// Creates a renderer drawing untagged text in `color`.
+    pub fn new(font: BitmapFont, color: Rgb) -> Markup {
+        Markup { font, color, icons: HashMap::new(), rects: LineRects::default() }
+    }
+
+    /// Changes where [draw_line](#method.draw_line) expects the SDK's lines, see
+    /// [LineRects](../text/struct.LineRects.html).
+    pub fn line_rects(mut self, rects: LineRects) -> Markup {
+        self.rects = rects;
+        self
+    }
+
+    /// Registers an icon for `[icon:name]` tags. Set pixels are drawn in the current color,
+    /// the icon is vertically centered on the text.
+    pub fn icon(mut self, name: &str, icon: PackedMonoBitmap) -> Markup {
+        self.icons.insert(name.to_owned(), icon);
+        self
+    }
+
+    /// Registers every [bundled icon](../icon/enum.Icon.html) under its
+    /// [name](../icon/enum.Icon.html#method.name), for tags like `[icon:battery]`.
+    pub fn builtin_icons(mut self, size: IconSize) -> Markup {
+        for &icon in Icon::ALL.iter() {
+            self.icons.insert(icon.name().to_owned(), icon.bitmap(size));
+        }
+        self
+    }
+
+    /// Width in pixels of the rendered markup.
+    ///
+    /// Return value:
+    /// [Error::Markup](../enum.Error.html) for malformed tags, unknown colors and icons.
+    ///
+    pub fn measure(&self, markup: &str) -> Result<usize, Error> {
+        let spans = self.parse(markup)?;
+        Ok(spans.iter().map(|span| self.span_width(span)).sum())
+    }
+
+    /// Draws markup onto a color canvas on a single line. Newlines are drawn as spaces.
+    ///
+    /// Parameters:
+    /// - x: Horizontal anchor of the line, see [Align](../font/enum.Align.html).
+    /// - y: Top of the line.
+    ///
+    /// Return value:
+    /// The width in pixels of the line, or [Error::Markup](../enum.Error.html) for malformed
+    /// markup, in which case nothing is drawn.
+    ///
+    pub fn draw<C>(&self, canvas: &mut C, markup: &str, x: isize, y: isize, align: Align)
+        -> Result<usize, Error>
+        where C: Canvas<Pixel = Argb>
+    {
+        let spans = self.parse(markup)?;
+        let width: usize = spans.iter().map(|span| self.span_width(span)).sum();
+        let mut left = match align {
+            Align::Left => x,
+            Align::Center => x - width as isize / 2,
+            Align::Right => x - width as isize,
+        };
+
+        for span in spans.iter() {
+            match *span {
+                Span::Text(ref text, style) => {
+                    let color = Argb::from(style.color);
+                    self.font.draw(canvas, text, left, y, Align::Left, color);
+                    if style.bold {
+                        self.font.draw(canvas, text, left + 1, y, Align::Left, color);
+                    }
+                },
+                Span::Icon(name, style) => {
+                    let icon = &self.icons[name];
+                    let top = y + (self.font.height() as isize - icon.height() as isize) / 2;
+                    draw_icon(canvas, icon, left, top, Argb::from(style.color));
+                },
+            }
+            left += self.span_width(span) as isize;
+        }
+        Ok(width)
+    }
+
+    /// Draws markup at roughly the position the SDK draws a color text line or the title,
+    /// vertically centered within it. Text is not clipped to the line.
+    ///
+    /// The SDK doesn't document where it draws its lines, the positions are taken from
+    /// [line_rects](#method.line_rects), a guess unless set to measured ones.
+    ///
+    /// Return value:
+    /// The width in pixels of the line, or [Error::Markup](../enum.Error.html) for malformed
+    /// markup, in which case nothing is drawn.
+    ///
+    /// Panics:
+    /// - If line is a monochrome line or a color line larger than 7.
+    ///
+    pub fn draw_line<C>(&self, canvas: &mut C, line: TextLine, markup: &str, align: Align)
+        -> Result<usize, Error>
+        where C: Canvas<Pixel = Argb>
+    {
+        match line {
+            TextLine::Mono(_) => panic!("Markup is only rendered onto color lines"),
+            TextLine::Color(line_number) => assert!(line_number < 8),
+            TextLine::ColorTitle => {},
+        }
+
+        let rect = self.rects.rect(line);
+        let x = match align {
+            Align::Left => rect.x,
+            Align::Center => rect.x + rect.width / 2,
+            Align::Right => rect.x + rect.width,
+        };
+        let y = rect.y as isize + (rect.height as isize - self.font.height() as isize) / 2;
+        self.draw(canvas, markup, x as isize, y, align)
+    }
+
+    fn span_width(&self, span: &Span) -> usize {
+        match *span {
+            Span::Text(ref text, style) => {
+                text.chars().count() * self.font.width() + style.bold as usize
+            },
+            Span::Icon(name, _) => self.icons[name].width() + ICON_SPACING,
+        }
+    }
+
+    fn parse<'a>(&self, markup: &'a str) -> Result<Vec<Span<'a>>, Error> {
+        let mut spans = Vec::new();
+        let mut stack = vec![Style { color: self.color, bold: false }];
+        let mut text = String::new();
+        let mut rest = markup;
+
+        while let Some(open) = rest.find('[') {
+            text.push_str(&rest[..open]);
+            rest = &rest[open + 1..];
+            if rest.starts_with('[') {
+                text.push('[');
+                rest = &rest[1..];
+                continue;
+            }
+
+            let close = rest.find(']').ok_or(Error::Markup("Unclosed markup tag."))?;
+            let tag = &rest[..close];
+            rest = &rest[close + 1..];
+
+            let style = *stack.last().unwrap();
+            if !text.is_empty() {
+                spans.push(Span::Text(text.replace('\n', " "), style));
+                text.clear();
+            }
+
+            if tag == "/" {
+                if stack.len() == 1 {
+                    return Err(Error::Markup("Closing tag without an open tag."));
+                }
+                stack.pop();
+            } else if tag == "b" {
+                stack.push(Style { bold: true, ..style });
+            } else if let Some(name) = tag.strip_prefix("icon:") {
+                if !self.icons.contains_key(name) {
+                    return Err(Error::Markup("Unknown icon."));
+                }
+                spans.push(Span::Icon(name, style));
+            } else {
+                let color = tag.parse::<Rgb>().map_err(|_| Error::Markup("Unknown markup tag."))?;
+                stack.push(Style { color, ..style });
+            }
+        }
+
+        text.push_str(rest);
+        if !text.is_empty() {
+            spans.push(Span::Text(text.replace('\n', " "), *stack.last().unwrap()));
+        }
+        Ok(spans)
+    }
+}
+
+fn draw_icon<C: Canvas<Pixel = Argb>>(canvas: &mut C, icon: &PackedMonoBitmap, x: isize,
+    y: isize, color: Argb)
+{
+    for row in 0..icon.height() {
+        for col in 0..icon.width() {
+            if icon.get(col, row) {
+                canvas.put_pixel(x + col as isize, y + row as isize, color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use canvas::ColorCanvas;
+    use color::{Argb, Rgb};
+    use font::{Align, FONT_8X13};
+    use packed::PackedMonoBitmap;
+    use text::TextLine;
+    use Error;
+    use {COLOR_WIDTH, COLOR_HEIGHT};
+    use super::{Markup, Span, Style};
+
+    const WHITE: Rgb = Rgb::new(255, 255, 255);
+    const RED: Rgb = Rgb::new(255, 0, 0);
+
+    fn markup() -> Markup {
+        Markup::new(FONT_8X13, WHITE).icon("dot", PackedMonoBitmap::new(3, 3))
+    }
+
+    fn text(s: &str, color: Rgb, bold: bool) -> Span<'static> {
+        Span::Text(s.to_owned(), Style { color, bold })
+    }
+
+    fn error(markup: &str) -> Option<&'static str> {
+        match self::markup().measure(markup) {
+            Err(Error::Markup(msg)) => Some(msg),
+            Err(e) => panic!("unexpected error {:?}", e),
+            Ok(_) => None,
+        }
+    }
+
+    #[test]
+    fn parses_nested_tags() {
+        let spans = markup().parse("a[red]b[b]c[/]d[/]e").unwrap();
+        assert_eq!(spans, [text("a", WHITE, false), text("b", RED, false), text("c", RED, true),
+            text("d", RED, false), text("e", WHITE, false)]);
+        let spans = markup().parse("[#00f][icon:dot]x").unwrap();
+        let blue = Style { color: Rgb::new(0, 0, 255), bold: false };
+        assert_eq!(spans, [Span::Icon("dot", blue), Span::Text("x".to_owned(), blue)]);
+    }
+
+    #[test]
+    fn open_tags_close_at_the_end() {
+        assert_eq!(markup().parse("[red]abc").unwrap(), [text("abc", RED, false)]);
+    }
+
+    #[test]
+    fn escapes_brackets() {
+        assert_eq!(markup().parse("[[red] x]").unwrap(), [text("[red] x]", WHITE, false)]);
+        assert_eq!(markup().parse("[red][[[/]").unwrap(), [text("[", RED, false)]);
+        assert_eq!(markup().parse("").unwrap(), []);
+    }
+
+    #[test]
+    fn draws_newlines_as_spaces() {
+        assert_eq!(markup().parse("a\nb").unwrap(), [text("a b", WHITE, false)]);
+    }
+
+    #[test]
+    fn rejects_malformed_markup() {
+        assert_eq!(error("[red"), Some("Unclosed markup tag."));
+        assert_eq!(error("abc [b"), Some("Unclosed markup tag."));
+        assert_eq!(error("[/]"), Some("Closing tag without an open tag."));
+        assert_eq!(error("[b]x[/][/]"), Some("Closing tag without an open tag."));
+        assert_eq!(error("[blurple]x"), Some("Unknown markup tag."));
+        assert_eq!(error("[]"), Some("Unknown markup tag."));
+        assert_eq!(error("[icon:nope]"), Some("Unknown icon."));
+        assert_eq!(error("[red]x[/] [b]y"), None);
+    }
+
+    #[test]
+    fn measures_text_bold_and_icons() {
+        let markup = markup();
+        assert_eq!(markup.measure("abc").unwrap(), 24);
+        assert_eq!(markup.measure("[b]abc[/]").unwrap(), 25);
+        assert_eq!(markup.measure("[icon:dot]ab").unwrap(), 3 + 1 + 16);
+    }
+
+    #[test]
+    fn draws_nothing_for_malformed_markup() {
+        let mut background = vec![0u8; COLOR_WIDTH * COLOR_HEIGHT * 4];
+        let ret = markup().draw_line(&mut ColorCanvas::new(&mut background), TextLine::Color(0),
+            "ok [nope]", Align::Left);
+        assert!(ret.is_err());
+        assert!(background.iter().all(|&b| b == 0));
+
+        let ret = markup().draw(&mut ColorCanvas::new(&mut background), "[red]I", 0, 0,
+            Align::Left);
+        assert_eq!(ret.unwrap(), 8);
+        assert!(background.chunks(4).any(|px| Argb::from_bgra(px) == Argb::from(RED)));
+    }
+}
@@ -0,0 +1,287 @@
+//! Scrolling text lines.
+//!
+//! Text longer than its line is clipped by the SDK. A marquee scrolls it through the line
+//! instead: the driver keeps the full text and moves a line-wide window over it every time
+//! [update](../struct.Driver.html#method.update) is called, so the application sets the
+//! text once and keeps calling update as usual. Text that fits its line is shown as is.
+//!
+//! Marquees are set with
+//! [set_mono_marquee](../struct.Driver.html#method.set_mono_marquee),
+//! [set_color_title_marquee](../struct.Driver.html#method.set_color_title_marquee) and
+//! [set_color_marquee](../struct.Driver.html#method.set_color_marquee). Setting the text of
+//! a line any other way stops its marquee.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use logitech_lcd::color::Rgb;
+//! use logitech_lcd::marquee::{Marquee, MarqueeMode};
+//!
+//! let mut driver = logitech_lcd::Driver::init_color("Marquee").unwrap();
+//!
+//! let marquee = Marquee::new(8.0)
+//!     .pause(Duration::from_millis(1500))
+//!     .mode(MarqueeMode::Bounce);
+//! driver.set_color_marquee(0, "Now playing: a song with a rather long title",
+//!     Rgb::new(255, 255, 255), marquee).unwrap();
+//!
+//! loop {
+//!     driver.update();
+//!     std::thread::sleep(Duration::from_millis(16));
+//! }
+//! ```
+
+use std::time::{Duration, Instant};
+
+use color::Rgb;
+use text::TextLine;
+
+/// Spaces between the end of the text and its next repetition in
+/// [Loop](enum.MarqueeMode.html#variant.Loop) mode.
+const LOOP_GAP: usize = 4;
+
+/// How a marquee continues at the end of the text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarqueeMode {
+    /// Scrolls on into a repetition of the text, pausing when the end of the text reaches
+    /// the end of the line and when the text is back at the start.
+    Loop,
+    /// Reverses direction, pausing at both ends.
+    Bounce,
+}
+
+/// Marquee settings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Marquee {
+    step: Duration,
+    pause: Duration,
+    mode: MarqueeMode,
+}
+
+impl Marquee {
+    /// Creates a looping marquee scrolling `speed` characters per second, pausing for a
+    /// second at the start.
+    ///
+    /// Panics:
+    /// - If speed is not positive and finite.
+    /// - If speed is so slow that a single step is longer than a Duration can hold.
+    ///
+    pub fn new(speed: f32) -> Marquee {
+        assert!(speed > 0.0 && speed.is_finite());
+        let step = Duration::try_from_secs_f32(1.0 / speed)
+            .expect("Marquee speed is too slow");
+        Marquee { step, pause: Duration::from_secs(1), mode: MarqueeMode::Loop }
+    }
+
+    /// Changes how long the marquee stops at the ends of the text.
+    pub fn pause(mut self, pause: Duration) -> Marquee {
+        self.pause = pause;
+        self
+    }
+
+    /// Changes what happens at the end of the text.
+    pub fn mode(mut self, mode: MarqueeMode) -> Marquee {
+        self.mode = mode;
+        self
+    }
+}
+
+impl Default for Marquee {
+    fn default() -> Marquee {
+        Marquee::new(4.0)
+    }
+}
+
+/// The scroll position of one line.
+#[derive(Debug)]
+pub(crate) struct Scroller {
+    line: TextLine,
+    color: Rgb,
+    chars: Vec<char>,
+    width: usize,
+    marquee: Marquee,
+    offset: usize,
+    backwards: bool,
+    next_step: Instant,
+}
+
+impl Scroller {
+    pub fn new(line: TextLine, width: usize, text: &str, color: Rgb, marquee: Marquee,
+        now: Instant) -> Scroller
+    {
+        Scroller {
+            line,
+            color,
+            chars: text.chars().collect(),
+            width,
+            marquee,
+            offset: 0,
+            backwards: false,
+            next_step: later(now, marquee.pause),
+        }
+    }
+
+    pub fn line(&self) -> TextLine {
+        self.line
+    }
+
+    pub fn color(&self) -> Rgb {
+        self.color
+    }
+
+    /// The part of the text currently on the line.
+    pub fn window(&self) -> String {
+        if self.chars.len() <= self.width {
+            return self.chars.iter().collect();
+        }
+        match self.marquee.mode {
+            MarqueeMode::Loop => {
+                let period = self.chars.len() + LOOP_GAP;
+                (self.offset..self.offset + self.width)
+                    .map(|i| self.chars.get(i % period).cloned().unwrap_or(' '))
+                    .collect()
+            },
+            MarqueeMode::Bounce => self.chars[self.offset..self.offset + self.width]
+                .iter().collect(),
+        }
+    }
+
+    /// Moves the window by one character if it is time to. Scrolls at most one character
+    /// per call, so the marquee slows down rather than jumps when update is called rarely.
+    ///
+    /// Return value:
+    /// `true` if the window moved.
+    ///
+    pub fn advance(&mut self, now: Instant) -> bool {
+        if self.chars.len() <= self.width || now < self.next_step {
+            return false;
+        }
+
+        let last = self.chars.len() - self.width;
+        let at_end = match self.marquee.mode {
+            MarqueeMode::Loop => {
+                self.offset = (self.offset + 1) % (self.chars.len() + LOOP_GAP);
+                self.offset == 0 || self.offset == last
+            },
+            MarqueeMode::Bounce => {
+                if self.backwards {
+                    self.offset -= 1;
+                } else {
+                    self.offset += 1;
+                }
+                let at_end = self.offset == 0 || self.offset == last;
+                if at_end {
+                    self.backwards = !self.backwards;
+                }
+                at_end
+            },
+        };
+
+        let delay = if at_end {
+            self.marquee.step.saturating_add(self.marquee.pause)
+        } else {
+            self.marquee.step
+        };
+        self.next_step = later(now, delay);
+        true
+    }
+}
+
+/// `now + delay`, saturating to a far future instant if that is not representable.
+fn later(now: Instant, delay: Duration) -> Instant {
+    let mut delay = delay;
+    loop {
+        match now.checked_add(delay) {
+            Some(instant) => return instant,
+            None => delay /= 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use color::Rgb;
+    use text::TextLine;
+    use super::{Marquee, MarqueeMode, Scroller};
+
+    #[test]
+    #[should_panic(expected = "too slow")]
+    fn rejects_unrepresentable_speeds() {
+        Marquee::new(1e-20);
+    }
+
+    #[test]
+    fn saturates_huge_pauses() {
+        let marquee = Marquee::new(1e30).pause(Duration::MAX);
+        let now = Instant::now();
+        let mut scroller = Scroller::new(TextLine::Mono(0), 26, &"x".repeat(64),
+            Rgb::new(0, 0, 0), marquee, now);
+        assert!(!scroller.advance(now + Duration::from_secs(3600)));
+    }
+
+    /// Advances the scroller once per step and records the window after each move, along
+    /// with whether it had to wait out a pause to get there.
+    fn run(mode: MarqueeMode, steps: usize) -> Vec<(String, bool)> {
+        let marquee = Marquee::new(1.0).pause(Duration::from_secs(10)).mode(mode);
+        let mut now = Instant::now();
+        let mut scroller = Scroller::new(TextLine::Color(0), 4, "abcdef", Rgb::new(0, 0, 0),
+            marquee, now);
+        let mut windows = vec![(scroller.window(), true)];
+        for _ in 0..steps {
+            now += Duration::from_secs(1);
+            let paused = !scroller.advance(now);
+            if paused {
+                now += Duration::from_secs(10);
+                assert!(scroller.advance(now));
+            }
+            windows.push((scroller.window(), paused));
+        }
+        windows
+    }
+
+    #[test]
+    fn loops_through_a_full_cycle() {
+        let windows = run(MarqueeMode::Loop, 11);
+        let expected = [
+            ("abcd", true),
+            ("bcde", true),
+            ("cdef", false),
+            ("def ", true),
+            ("ef  ", false),
+            ("f   ", false),
+            ("    ", false),
+            ("   a", false),
+            ("  ab", false),
+            (" abc", false),
+            ("abcd", false),
+            ("bcde", true),
+        ];
+        let expected: Vec<_> = expected.iter().map(|&(w, p)| (w.to_owned(), p)).collect();
+        assert_eq!(windows, expected);
+    }
+
+    #[test]
+    fn bounces_through_a_full_cycle() {
+        let windows = run(MarqueeMode::Bounce, 5);
+        let expected = [
+            ("abcd", true),
+            ("bcde", true),
+            ("cdef", false),
+            ("bcde", true),
+            ("abcd", false),
+            ("bcde", true),
+        ];
+        let expected: Vec<_> = expected.iter().map(|&(w, p)| (w.to_owned(), p)).collect();
+        assert_eq!(windows, expected);
+    }
+
+    #[test]
+    fn text_that_fits_does_not_scroll() {
+        let now = Instant::now();
+        let mut scroller = Scroller::new(TextLine::Mono(0), 26, "short", Rgb::new(0, 0, 0),
+            Marquee::new(1e3).pause(Duration::ZERO), now);
+        assert!(!scroller.advance(now + Duration::from_secs(1)));
+        assert_eq!(scroller.window(), "short");
+    }
+}
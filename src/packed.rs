@@ -0,0 +1,419 @@
+//! Packed 1-bit monochrome bitmaps.
+//!
+//! The SDK takes one byte per pixel for the monochrome LCD, 6880 bytes for a full screen.
+//! A [PackedMonoBitmap](struct.PackedMonoBitmap.html) stores one bit per pixel instead,
+//! 880 bytes for a full screen, which makes it cheap to keep many frames around and to
+//! compare or combine them with bitwise operations.
+//!
+//! Bits are stored row-major with each row padded to a whole byte, least significant bit
+//! first. This is the layout of the `_bits` array of an X11 XBM file, so XBM data can be
+//! used directly with [from_bytes](struct.PackedMonoBitmap.html#method.from_bytes).
+//!
+//! ```
+//! use logitech_lcd::packed::{BlitOp, PackedMonoBitmap};
+//!
+//! let mut frame = PackedMonoBitmap::screen();
+//! let icon = PackedMonoBitmap::from_bytes(8, 2, vec![0xff, 0x81]);
+//! frame.blit(&icon, 10, 10, BlitOp::Or);
+//!
+//! let previous = PackedMonoBitmap::screen();
+//! assert_eq!(frame.diff_count(&previous), 10);
+//!
+//! let background = frame.to_mono_background();
+//! assert_eq!(background.len(), logitech_lcd::MONO_WIDTH * logitech_lcd::MONO_HEIGHT);
+//! ```
+
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+use {MONO_WIDTH, MONO_HEIGHT};
+
+/// How source bits are combined with destination bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlitOp {
+    /// Replace the destination.
+    Copy,
+    /// Turn on pixels that are on in the source.
+    Or,
+    /// Keep pixels that are on in both.
+    And,
+    /// Toggle pixels that are on in the source.
+    Xor,
+    /// Turn off pixels that are on in the source.
+    Clear,
+}
+
+impl BlitOp {
+    /// Combines destination bits with source bits, 8 pixels at a time.
+    pub(crate) fn apply(self, dst: u8, src: u8) -> u8 {
+        match self {
+            BlitOp::Copy => src,
+            BlitOp::Or => dst | src,
+            BlitOp::And => dst & src,
+            BlitOp::Xor => dst ^ src,
+            BlitOp::Clear => dst & !src,
+        }
+    }
+}
+
+/// A monochrome bitmap with 1 bit per pixel.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PackedMonoBitmap {
+    width: usize,
+    height: usize,
+    stride: usize,
+    bits: Vec<u8>,
+}
+
+impl PackedMonoBitmap {
+    /// Creates a bitmap with all pixels off.
+    pub fn new(width: usize, height: usize) -> PackedMonoBitmap {
+        let stride = width.div_ceil(8);
+        PackedMonoBitmap {
+            width,
+            height,
+            stride,
+            bits: vec![0; stride * height],
+        }
+    }
+
+    /// Creates a bitmap the size of the monochrome LCD, 160x43, with all pixels off.
+    pub fn screen() -> PackedMonoBitmap {
+        PackedMonoBitmap::new(MONO_WIDTH, MONO_HEIGHT)
+    }
+
+    /// Creates a bitmap from packed bits in XBM layout.
+    ///
+    /// Panics:
+    /// - If bits' length is not `ceil(width / 8) * height` bytes.
+    ///
+    pub fn from_bytes(width: usize, height: usize, bits: Vec<u8>) -> PackedMonoBitmap {
+        let stride = width.div_ceil(8);
+        assert_eq!(bits.len(), stride * height);
+
+        let mut bitmap = PackedMonoBitmap { width, height, stride, bits };
+        bitmap.clear_padding();
+        bitmap
+    }
+
+    /// Packs an 8-bit grayscale frame, pixels with a value >= 128 are on.
+    ///
+    /// Panics:
+    /// - If luma's length is not `width * height` bytes.
+    ///
+    pub fn from_luma(width: usize, height: usize, luma: &[u8]) -> PackedMonoBitmap {
+        assert_eq!(luma.len(), width * height);
+
+        let mut bitmap = PackedMonoBitmap::new(width, height);
+        if width == 0 {
+            return bitmap;
+        }
+        for (row, src) in bitmap.bits.chunks_mut(bitmap.stride).zip(luma.chunks(width)) {
+            for (byte, px) in row.iter_mut().zip(src.chunks(8)) {
+                *byte = px.iter().enumerate()
+                    .fold(0, |b, (i, &l)| if l >= 128 { b | 1 << i } else { b });
+            }
+        }
+        bitmap
+    }
+
+    /// Packs a monochrome background, pixels with a value >= 128 are on.
+    ///
+    /// Panics:
+    /// - If mono_bitmap's length is not 160x43 bytes.
+    ///
+    pub fn from_mono_background(mono_bitmap: &[u8]) -> PackedMonoBitmap {
+        PackedMonoBitmap::from_luma(MONO_WIDTH, MONO_HEIGHT, mono_bitmap)
+    }
+
+    /// Width in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Bytes per row.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Packed bits in XBM layout.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bits
+    }
+
+    /// Returns `true` if the pixel is on. Pixels outside the bitmap are off.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height &&
+            self.bits[y * self.stride + x / 8] & (1 << (x % 8)) != 0
+    }
+
+    /// Turns a pixel on or off. Pixels outside the bitmap are ignored.
+    pub fn set(&mut self, x: usize, y: usize, on: bool) {
+        if x < self.width && y < self.height {
+            let byte = &mut self.bits[y * self.stride + x / 8];
+            if on {
+                *byte |= 1 << (x % 8);
+            } else {
+                *byte &= !(1 << (x % 8));
+            }
+        }
+    }
+
+    /// Toggles a pixel. Pixels outside the bitmap are ignored.
+    pub fn toggle(&mut self, x: usize, y: usize) {
+        if x < self.width && y < self.height {
+            self.bits[y * self.stride + x / 8] ^= 1 << (x % 8);
+        }
+    }
+
+    /// Turns all pixels on or off.
+    pub fn fill(&mut self, on: bool) {
+        for b in self.bits.iter_mut() {
+            *b = if on { 0xff } else { 0 };
+        }
+        self.clear_padding();
+    }
+
+    /// Toggles all pixels.
+    pub fn invert(&mut self) {
+        for b in self.bits.iter_mut() {
+            *b = !*b;
+        }
+        self.clear_padding();
+    }
+
+    /// Number of pixels that are on.
+    pub fn count_ones(&self) -> usize {
+        self.bits.iter().map(|b| b.count_ones() as usize).sum()
+    }
+
+    /// Number of pixels that differ from `other`.
+    ///
+    /// Panics:
+    /// - If the bitmaps have different dimensions.
+    ///
+    pub fn diff_count(&self, other: &PackedMonoBitmap) -> usize {
+        self.assert_same_size(other);
+        self.bits.iter().zip(other.bits.iter())
+            .map(|(a, b)| (a ^ b).count_ones() as usize)
+            .sum()
+    }
+
+    /// Combines `src` into this bitmap with its top left corner at (x, y).
+    /// The parts of `src` that fall outside this bitmap are clipped.
+    ///
+    /// Works on whole bytes, source rows are shifted into place when x is not a multiple
+    /// of 8.
+    pub fn blit(&mut self, src: &PackedMonoBitmap, x: isize, y: isize, op: BlitOp) {
+        let left = x.max(0);
+        let right = (x + src.width as isize).min(self.width as isize);
+        if left >= right {
+            return;
+        }
+        let (left, right) = (left as usize, right as usize);
+
+        for sy in 0..src.height {
+            let dy = y + sy as isize;
+            if dy < 0 || dy as usize >= self.height {
+                continue;
+            }
+            let src_row = &src.bits[sy * src.stride..(sy + 1) * src.stride];
+            let dst_row = &mut self.bits[dy as usize * self.stride..][..self.stride];
+
+            for (i, d) in dst_row.iter_mut().enumerate().take(right.div_ceil(8)).skip(left / 8) {
+                // The bits of this byte inside the clipped range.
+                let lo = left.max(i * 8) - i * 8;
+                let hi = right.min(i * 8 + 8) - i * 8;
+                let mask = ((0xffu16 << lo) & !(0xffu16 << hi)) as u8;
+
+                let s = row_byte(src_row, (i * 8) as isize - x);
+                *d = *d & !mask | op.apply(*d, s) & mask;
+            }
+        }
+    }
+
+    /// Expands into an 8-bit grayscale buffer, on pixels are 255 and off pixels are 0.
+    ///
+    /// Panics:
+    /// - If dst's length is not `width * height` bytes.
+    ///
+    pub fn write_luma(&self, dst: &mut [u8]) {
+        assert_eq!(dst.len(), self.width * self.height);
+        if self.width == 0 {
+            return;
+        }
+        for (row, out) in self.bits.chunks(self.stride).zip(dst.chunks_mut(self.width)) {
+            for (byte, px) in row.iter().zip(out.chunks_mut(8)) {
+                for (i, p) in px.iter_mut().enumerate() {
+                    *p = if byte & (1 << i) != 0 { 255 } else { 0 };
+                }
+            }
+        }
+    }
+
+    /// Expands into a newly allocated monochrome background.
+    ///
+    /// Panics:
+    /// - If the bitmap is not 160x43 pixels.
+    ///
+    pub fn to_mono_background(&self) -> Vec<u8> {
+        assert!(self.width == MONO_WIDTH && self.height == MONO_HEIGHT);
+        let mut dst = vec![0; MONO_WIDTH * MONO_HEIGHT];
+        self.write_luma(&mut dst);
+        dst
+    }
+
+    fn assert_same_size(&self, other: &PackedMonoBitmap) {
+        assert!(self.width == other.width && self.height == other.height);
+    }
+
+    /// Keeps the unused bits at the end of each row zero, so whole bytes can be compared.
+    fn clear_padding(&mut self) {
+        let used = self.width % 8;
+        if used == 0 {
+            return;
+        }
+        let mask = (1u8 << used) - 1;
+        let stride = self.stride;
+        for row in self.bits.chunks_mut(stride) {
+            row[stride - 1] &= mask;
+        }
+    }
+
+    fn zip_bytes<F: Fn(u8, u8) -> u8>(&mut self, other: &PackedMonoBitmap, f: F) {
+        self.assert_same_size(other);
+        for (a, b) in self.bits.iter_mut().zip(other.bits.iter()) {
+            *a = f(*a, *b);
+        }
+    }
+}
+
+/// The 8 pixels of a packed row starting at pixel `start`, as a byte in row layout. Pixels
+/// outside the row are off.
+fn row_byte(row: &[u8], start: isize) -> u8 {
+    let byte = |i: isize| if i < 0 { 0 } else { row.get(i as usize).cloned().unwrap_or(0) };
+    let index = start.div_euclid(8);
+    let shift = start.rem_euclid(8);
+    if shift == 0 {
+        byte(index)
+    } else {
+        byte(index) >> shift | byte(index + 1) << (8 - shift)
+    }
+}
+
+impl BitAndAssign<&PackedMonoBitmap> for PackedMonoBitmap {
+    fn bitand_assign(&mut self, rhs: &PackedMonoBitmap) {
+        self.zip_bytes(rhs, |a, b| a & b);
+    }
+}
+
+impl BitOrAssign<&PackedMonoBitmap> for PackedMonoBitmap {
+    fn bitor_assign(&mut self, rhs: &PackedMonoBitmap) {
+        self.zip_bytes(rhs, |a, b| a | b);
+    }
+}
+
+impl BitXorAssign<&PackedMonoBitmap> for PackedMonoBitmap {
+    fn bitxor_assign(&mut self, rhs: &PackedMonoBitmap) {
+        self.zip_bytes(rhs, |a, b| a ^ b);
+    }
+}
+
+impl BitAnd<&PackedMonoBitmap> for &PackedMonoBitmap {
+    type Output = PackedMonoBitmap;
+
+    fn bitand(self, rhs: &PackedMonoBitmap) -> PackedMonoBitmap {
+        let mut out = self.clone();
+        out &= rhs;
+        out
+    }
+}
+
+impl BitOr<&PackedMonoBitmap> for &PackedMonoBitmap {
+    type Output = PackedMonoBitmap;
+
+    fn bitor(self, rhs: &PackedMonoBitmap) -> PackedMonoBitmap {
+        let mut out = self.clone();
+        out |= rhs;
+        out
+    }
+}
+
+impl BitXor<&PackedMonoBitmap> for &PackedMonoBitmap {
+    type Output = PackedMonoBitmap;
+
+    fn bitxor(self, rhs: &PackedMonoBitmap) -> PackedMonoBitmap {
+        let mut out = self.clone();
+        out ^= rhs;
+        out
+    }
+}
+
+impl Not for &PackedMonoBitmap {
+    type Output = PackedMonoBitmap;
+
+    fn not(self) -> PackedMonoBitmap {
+        let mut out = self.clone();
+        out.invert();
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BlitOp, PackedMonoBitmap};
+
+    /// A pattern without repeats along a row, so misaligned shifts show up.
+    fn pattern(width: usize, height: usize, seed: usize) -> PackedMonoBitmap {
+        let mut bitmap = PackedMonoBitmap::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                bitmap.set(x, y, (x * 7 + y * 13 + seed) % 5 < 2);
+            }
+        }
+        bitmap
+    }
+
+    fn blit_per_pixel(dst: &mut PackedMonoBitmap, src: &PackedMonoBitmap, x: isize, y: isize,
+        op: BlitOp)
+    {
+        for sy in 0..src.height() {
+            for sx in 0..src.width() {
+                let (dx, dy) = (x + sx as isize, y + sy as isize);
+                if dx < 0 || dy < 0 {
+                    continue;
+                }
+                let (dx, dy) = (dx as usize, dy as usize);
+                let (s, d) = (src.get(sx, sy), dst.get(dx, dy));
+                dst.set(dx, dy, match op {
+                    BlitOp::Copy => s,
+                    BlitOp::Or => d | s,
+                    BlitOp::And => d & s,
+                    BlitOp::Xor => d ^ s,
+                    BlitOp::Clear => d & !s,
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn blit_matches_per_pixel_blit() {
+        let ops = [BlitOp::Copy, BlitOp::Or, BlitOp::And, BlitOp::Xor, BlitOp::Clear];
+        let src = pattern(13, 5, 3);
+        for &op in ops.iter() {
+            for y in -6..=22 {
+                for x in -14..=30 {
+                    let mut fast = pattern(29, 21, 1);
+                    let mut slow = fast.clone();
+                    fast.blit(&src, x, y, op);
+                    blit_per_pixel(&mut slow, &src, x, y, op);
+                    assert_eq!(fast, slow, "{:?} at {}, {}", op, x, y);
+                }
+            }
+        }
+    }
+}
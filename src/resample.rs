@@ -0,0 +1,498 @@
+//! Image resampling for LCD backgrounds.
+//!
+//! Resize arbitrary sized frames to the resolution of the LCD without depending on an image
+//! library. Source frames are tightly packed, row-major, 8 bits per channel buffers described
+//! by a [Format](enum.Format.html). The helpers [color_background](fn.color_background.html)
+//! and [mono_background](fn.mono_background.html) produce buffers in exactly the layout
+//! expected by [set_color_background](../struct.Driver.html#method.set_color_background) and
+//! [set_mono_background](../struct.Driver.html#method.set_mono_background).
+//!
+//! ```
+//! use logitech_lcd::resample::{self, Filter, Fit, Format};
+//!
+//! // A 64x64 opaque red square.
+//! let frame = [255u8, 0, 0, 255].iter().cloned().cycle().take(64 * 64 * 4).collect::<Vec<u8>>();
+//!
+//! let color = resample::color_background(&frame, 64, 64, Format::Rgba,
+//!     Filter::Bilinear, Fit::Letterbox);
+//! assert_eq!(color.len(), logitech_lcd::COLOR_WIDTH * logitech_lcd::COLOR_HEIGHT * 4);
+//!
+//! let mono = resample::mono_background(&frame, 64, 64, Format::Rgba,
+//!     Filter::Nearest, Fit::Crop);
+//! assert_eq!(mono.len(), logitech_lcd::MONO_WIDTH * logitech_lcd::MONO_HEIGHT);
+//! ```
+
+use std::f32::consts::PI;
+
+use {COLOR_WIDTH, COLOR_HEIGHT, MONO_WIDTH, MONO_HEIGHT};
+
+pub use canvas::Rect;
+
+/// Resampling filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Filter {
+    /// Nearest neighbor, fastest and keeps hard pixel edges.
+    Nearest,
+    /// Bilinear interpolation, area averaged when downscaling.
+    Bilinear,
+    /// Lanczos with a window of 3 lobes, sharpest but slowest.
+    Lanczos3,
+}
+
+/// How a frame is fitted to a target with a different aspect ratio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fit {
+    /// Scale both axes independently to fill the target, distorting the frame.
+    Stretch,
+    /// Scale to fit inside the target, keeping the aspect ratio. The uncovered area is black.
+    Letterbox,
+    /// Scale to cover the whole target, keeping the aspect ratio. The overflow is cropped
+    /// equally from both sides.
+    Crop,
+}
+
+/// Pixel layout of a source frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// 8-bit grayscale, 1 byte per pixel.
+    Luma,
+    /// 8-bit RGB, 3 bytes per pixel.
+    Rgb,
+    /// 8-bit RGBA with straight alpha, 4 bytes per pixel.
+    Rgba,
+    /// 8-bit BGRA with straight alpha, 4 bytes per pixel. This is the layout used by the color LCD.
+    Bgra,
+}
+
+impl Format {
+    /// Number of bytes per pixel.
+    pub fn channels(&self) -> usize {
+        match *self {
+            Format::Luma => 1,
+            Format::Rgb => 3,
+            Format::Rgba | Format::Bgra => 4,
+        }
+    }
+}
+
+/// Resizes a frame to `dst_width` x `dst_height` pixels.
+///
+/// Parameters:
+/// - src: Source frame, `src_width * src_height * channels` bytes.
+/// - channels: Number of interleaved 8-bit channels per pixel, all channels are filtered
+///   independently.
+///
+/// Panics:
+/// - If src's length does not match the given dimensions.
+/// - If any dimension is zero.
+///
+pub fn resize(src: &[u8], src_width: usize, src_height: usize, channels: usize,
+    dst_width: usize, dst_height: usize, filter: Filter) -> Vec<u8>
+{
+    assert_eq!(src.len(), src_width * src_height * channels);
+    assert!(src_width > 0 && src_height > 0 && dst_width > 0 && dst_height > 0);
+
+    if src_width == dst_width && src_height == dst_height {
+        return src.to_vec();
+    }
+
+    match filter {
+        Filter::Nearest => resize_nearest(src, src_width, src_height, channels,
+            dst_width, dst_height),
+        Filter::Bilinear => resize_separable(src, src_width, src_height, channels,
+            dst_width, dst_height, TRIANGLE),
+        Filter::Lanczos3 => resize_separable(src, src_width, src_height, channels,
+            dst_width, dst_height, LANCZOS3),
+    }
+}
+
+/// Copies the area described by `rect` out of a frame.
+///
+/// Panics:
+/// - If src's length does not match the given dimensions.
+/// - If rect is not inside the frame.
+///
+pub fn crop(src: &[u8], width: usize, height: usize, channels: usize, rect: Rect) -> Vec<u8> {
+    assert_eq!(src.len(), width * height * channels);
+    assert!(rect.x + rect.width <= width && rect.y + rect.height <= height);
+
+    let mut dst = Vec::with_capacity(rect.width * rect.height * channels);
+    for y in rect.y..rect.y + rect.height {
+        let start = (y * width + rect.x) * channels;
+        dst.extend_from_slice(&src[start..start + rect.width * channels]);
+    }
+    dst
+}
+
+/// Returns where a `src_width` x `src_height` frame ends up inside the target when
+/// scaled to fit with the aspect ratio preserved.
+pub fn letterbox_rect(src_width: usize, src_height: usize, dst_width: usize, dst_height: usize)
+    -> Rect
+{
+    // Compare src_w / src_h against dst_w / dst_h without floating point.
+    let (width, height) = if src_width * dst_height >= dst_width * src_height {
+        (dst_width, (src_height * dst_width + src_width / 2) / src_width)
+    } else {
+        ((src_width * dst_height + src_height / 2) / src_height, dst_height)
+    };
+
+    let width = width.max(1).min(dst_width);
+    let height = height.max(1).min(dst_height);
+
+    Rect {
+        x: (dst_width - width) / 2,
+        y: (dst_height - height) / 2,
+        width,
+        height,
+    }
+}
+
+/// Returns the centered area of a `src_width` x `src_height` frame that has the aspect
+/// ratio of the target.
+pub fn crop_rect(src_width: usize, src_height: usize, dst_width: usize, dst_height: usize)
+    -> Rect
+{
+    let (width, height) = if src_width * dst_height >= dst_width * src_height {
+        ((dst_width * src_height + dst_height / 2) / dst_height, src_height)
+    } else {
+        (src_width, (dst_height * src_width + dst_width / 2) / dst_width)
+    };
+
+    let width = width.max(1).min(src_width);
+    let height = height.max(1).min(src_height);
+
+    Rect {
+        x: (src_width - width) / 2,
+        y: (src_height - height) / 2,
+        width,
+        height,
+    }
+}
+
+/// Resizes a frame to fit inside a `dst_width` x `dst_height` target with the aspect
+/// ratio preserved.
+///
+/// The result has the same number of channels as the source. The uncovered area is
+/// filled with zero bytes.
+///
+/// Panics:
+/// - If src's length does not match the given dimensions.
+/// - If any dimension is zero.
+///
+pub fn letterbox(src: &[u8], src_width: usize, src_height: usize, channels: usize,
+    dst_width: usize, dst_height: usize, filter: Filter) -> Vec<u8>
+{
+    assert_eq!(src.len(), src_width * src_height * channels);
+    assert!(src_width > 0 && src_height > 0 && dst_width > 0 && dst_height > 0);
+
+    let rect = letterbox_rect(src_width, src_height, dst_width, dst_height);
+    let scaled = resize(src, src_width, src_height, channels, rect.width, rect.height, filter);
+
+    let mut dst = vec![0u8; dst_width * dst_height * channels];
+    let row = rect.width * channels;
+    for y in 0..rect.height {
+        let start = ((rect.y + y) * dst_width + rect.x) * channels;
+        dst[start..start + row].copy_from_slice(&scaled[y * row..(y + 1) * row]);
+    }
+    dst
+}
+
+/// Resizes a frame to cover a `dst_width` x `dst_height` target with the aspect
+/// ratio preserved, cropping the overflow equally from both sides.
+///
+/// Panics:
+/// - If src's length does not match the given dimensions.
+/// - If any dimension is zero.
+///
+pub fn crop_to_fill(src: &[u8], src_width: usize, src_height: usize, channels: usize,
+    dst_width: usize, dst_height: usize, filter: Filter) -> Vec<u8>
+{
+    assert_eq!(src.len(), src_width * src_height * channels);
+    assert!(src_width > 0 && src_height > 0 && dst_width > 0 && dst_height > 0);
+
+    let rect = crop_rect(src_width, src_height, dst_width, dst_height);
+    let cropped = crop(src, src_width, src_height, channels, rect);
+    resize(&cropped, rect.width, rect.height, channels, dst_width, dst_height, filter)
+}
+
+fn fit_to(src: &[u8], width: usize, height: usize, channels: usize,
+    (dst_width, dst_height): (usize, usize), filter: Filter, fit: Fit) -> Vec<u8>
+{
+    match fit {
+        Fit::Stretch => resize(src, width, height, channels, dst_width, dst_height, filter),
+        Fit::Letterbox => letterbox(src, width, height, channels, dst_width, dst_height, filter),
+        Fit::Crop => crop_to_fill(src, width, height, channels, dst_width, dst_height, filter),
+    }
+}
+
+/// Converts a frame of any size into a color LCD background.
+///
+/// The result is 320x240 pixels in BGRA order, as expected by
+/// [set_color_background](../struct.Driver.html#method.set_color_background).
+/// Letterboxed areas are opaque black.
+///
+/// Panics:
+/// - If src's length does not match the given dimensions.
+/// - If width or height is zero.
+///
+pub fn color_background(src: &[u8], width: usize, height: usize, format: Format,
+    filter: Filter, fit: Fit) -> Vec<u8>
+{
+    assert_eq!(src.len(), width * height * format.channels());
+
+    let bgra = to_bgra(src, format);
+    let mut dst = fit_to(&bgra, width, height, 4, (COLOR_WIDTH, COLOR_HEIGHT), filter, fit);
+
+    if fit == Fit::Letterbox {
+        let rect = letterbox_rect(width, height, COLOR_WIDTH, COLOR_HEIGHT);
+        for (i, px) in dst.chunks_mut(4).enumerate() {
+            let (x, y) = (i % COLOR_WIDTH, i / COLOR_WIDTH);
+            if x < rect.x || x >= rect.x + rect.width || y < rect.y || y >= rect.y + rect.height {
+                px[3] = 255;
+            }
+        }
+    }
+
+    dst
+}
+
+/// Converts a frame of any size into a monochrome LCD background.
+///
+/// Color frames are converted to luma, and frames with alpha are composited onto black.
+/// The result is 160x43 bytes, one byte per pixel, as expected by
+/// [set_mono_background](../struct.Driver.html#method.set_mono_background). Pixels with a
+/// value >= 128 turns on.
+///
+/// Panics:
+/// - If src's length does not match the given dimensions.
+/// - If width or height is zero.
+///
+pub fn mono_background(src: &[u8], width: usize, height: usize, format: Format,
+    filter: Filter, fit: Fit) -> Vec<u8>
+{
+    assert_eq!(src.len(), width * height * format.channels());
+
+    let luma = to_luma(src, format);
+    fit_to(&luma, width, height, 1, (MONO_WIDTH, MONO_HEIGHT), filter, fit)
+}
+
+/// Converts a frame into BGRA.
+pub(crate) fn to_bgra(src: &[u8], format: Format) -> Vec<u8> {
+    let mut dst = Vec::with_capacity(src.len() / format.channels() * 4);
+    match format {
+        Format::Bgra => dst.extend_from_slice(src),
+        Format::Rgba => for p in src.chunks(4) {
+            dst.extend_from_slice(&[p[2], p[1], p[0], p[3]]);
+        },
+        Format::Rgb => for p in src.chunks(3) {
+            dst.extend_from_slice(&[p[2], p[1], p[0], 255]);
+        },
+        Format::Luma => for &l in src {
+            dst.extend_from_slice(&[l, l, l, 255]);
+        },
+    }
+    dst
+}
+
+/// Converts a frame into luma, composited onto black.
+pub(crate) fn to_luma(src: &[u8], format: Format) -> Vec<u8> {
+    match format {
+        Format::Luma => src.to_vec(),
+        Format::Rgb => src.chunks(3).map(|p| luma(p[0], p[1], p[2])).collect(),
+        Format::Rgba => src.chunks(4)
+            .map(|p| mul_alpha(luma(p[0], p[1], p[2]), p[3]))
+            .collect(),
+        Format::Bgra => src.chunks(4)
+            .map(|p| mul_alpha(luma(p[2], p[1], p[0]), p[3]))
+            .collect(),
+    }
+}
+
+/// Rec. 601 luma of a color in integer arithmetic, the brightness used when converting
+/// color frames for the monochrome LCD.
+pub fn luma(r: u8, g: u8, b: u8) -> u8 {
+    ((r as u32 * 299 + g as u32 * 587 + b as u32 * 114 + 500) / 1000) as u8
+}
+
+fn mul_alpha(v: u8, a: u8) -> u8 {
+    ((v as u32 * a as u32 + 127) / 255) as u8
+}
+
+fn resize_nearest(src: &[u8], src_width: usize, src_height: usize, channels: usize,
+    dst_width: usize, dst_height: usize) -> Vec<u8>
+{
+    let mut dst = Vec::with_capacity(dst_width * dst_height * channels);
+    for y in 0..dst_height {
+        let sy = ((2 * y + 1) * src_height / (2 * dst_height)).min(src_height - 1);
+        for x in 0..dst_width {
+            let sx = ((2 * x + 1) * src_width / (2 * dst_width)).min(src_width - 1);
+            let start = (sy * src_width + sx) * channels;
+            dst.extend_from_slice(&src[start..start + channels]);
+        }
+    }
+    dst
+}
+
+fn triangle(x: f32) -> f32 {
+    let x = x.abs();
+    if x < 1.0 { 1.0 - x } else { 0.0 }
+}
+
+fn sinc(x: f32) -> f32 {
+    if x == 0.0 {
+        1.0
+    } else {
+        let x = x * PI;
+        x.sin() / x
+    }
+}
+
+fn lanczos3(x: f32) -> f32 {
+    if x.abs() < 3.0 { sinc(x) * sinc(x / 3.0) } else { 0.0 }
+}
+
+/// A resampling kernel and the radius outside of which it is zero.
+struct Kernel {
+    support: f32,
+    f: fn(f32) -> f32,
+}
+
+const TRIANGLE: Kernel = Kernel { support: 1.0, f: triangle };
+const LANCZOS3: Kernel = Kernel { support: 3.0, f: lanczos3 };
+
+/// Contributions of source samples to one destination sample.
+struct Taps {
+    start: usize,
+    weights: Vec<f32>,
+}
+
+/// Precomputes normalized filter weights for resampling one axis.
+fn taps(src_len: usize, dst_len: usize, kernel: &Kernel) -> Vec<Taps> {
+    let scale = src_len as f32 / dst_len as f32;
+    // Widen the kernel when downscaling, so every source sample contributes.
+    let filter_scale = scale.max(1.0);
+    let radius = kernel.support * filter_scale;
+
+    (0..dst_len).map(|i| {
+        let center = (i as f32 + 0.5) * scale;
+        let start = (center - radius).floor().max(0.0) as usize;
+        let end = ((center + radius).ceil() as usize).min(src_len);
+
+        let mut weights = (start..end)
+            .map(|j| (kernel.f)((j as f32 + 0.5 - center) / filter_scale))
+            .collect::<Vec<f32>>();
+
+        let sum = weights.iter().fold(0.0, |a, w| a + w);
+        if sum != 0.0 {
+            for w in weights.iter_mut() {
+                *w /= sum;
+            }
+        }
+
+        Taps { start, weights }
+    }).collect()
+}
+
+fn resize_separable(src: &[u8], src_width: usize, src_height: usize, channels: usize,
+    dst_width: usize, dst_height: usize, kernel: Kernel) -> Vec<u8>
+{
+    // Horizontal pass into an intermediate src_height x dst_width float buffer.
+    let htaps = taps(src_width, dst_width, &kernel);
+    let mut tmp = vec![0f32; src_height * dst_width * channels];
+    for y in 0..src_height {
+        let row = &src[y * src_width * channels..(y + 1) * src_width * channels];
+        for (x, t) in htaps.iter().enumerate() {
+            let out = &mut tmp[(y * dst_width + x) * channels..(y * dst_width + x + 1) * channels];
+            for (k, w) in t.weights.iter().enumerate() {
+                let px = &row[(t.start + k) * channels..(t.start + k + 1) * channels];
+                for c in 0..channels {
+                    out[c] += px[c] as f32 * w;
+                }
+            }
+        }
+    }
+
+    // Vertical pass.
+    let vtaps = taps(src_height, dst_height, &kernel);
+    let mut dst = vec![0u8; dst_width * dst_height * channels];
+    let mut acc = vec![0f32; dst_width * channels];
+    for (y, t) in vtaps.iter().enumerate() {
+        for a in acc.iter_mut() {
+            *a = 0.0;
+        }
+        for (k, w) in t.weights.iter().enumerate() {
+            let row = &tmp[(t.start + k) * dst_width * channels..
+                (t.start + k + 1) * dst_width * channels];
+            for (a, v) in acc.iter_mut().zip(row.iter()) {
+                *a += v * w;
+            }
+        }
+        let out = &mut dst[y * dst_width * channels..(y + 1) * dst_width * channels];
+        for (o, a) in out.iter_mut().zip(acc.iter()) {
+            *o = a.round().clamp(0.0, 255.0) as u8;
+        }
+    }
+
+    dst
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crop_rect, crop_to_fill, letterbox, letterbox_rect, mono_background, resize,
+        color_background, Filter, Fit, Format, Rect};
+
+    const FILTERS: [Filter; 3] = [Filter::Nearest, Filter::Bilinear, Filter::Lanczos3];
+
+    fn rect(x: usize, y: usize, width: usize, height: usize) -> Rect {
+        Rect { x, y, width, height }
+    }
+
+    #[test]
+    fn identity_resize_copies_the_source() {
+        let src: Vec<u8> = (0..5 * 3 * 2).map(|v| v as u8 * 7).collect();
+        for &filter in FILTERS.iter() {
+            assert_eq!(resize(&src, 5, 3, 2, 5, 3, filter), src);
+            assert_eq!(letterbox(&src, 5, 3, 2, 5, 3, filter), src);
+            assert_eq!(crop_to_fill(&src, 5, 3, 2, 5, 3, filter), src);
+        }
+        assert_eq!(letterbox_rect(5, 3, 5, 3), rect(0, 0, 5, 3));
+        assert_eq!(crop_rect(5, 3, 5, 3), rect(0, 0, 5, 3));
+    }
+
+    #[test]
+    fn uniform_frames_stay_uniform() {
+        for &filter in FILTERS.iter() {
+            assert!(resize(&[200], 1, 1, 1, 7, 5, filter).iter().all(|&v| v == 200));
+            assert!(resize(&[90; 64 * 48], 64, 48, 1, 5, 3, filter).iter().all(|&v| v == 90));
+        }
+    }
+
+    #[test]
+    fn fits_single_pixel_sources() {
+        assert_eq!(letterbox_rect(1, 1, 320, 240), rect(40, 0, 240, 240));
+        assert_eq!(crop_rect(1, 1, 320, 240), rect(0, 0, 1, 1));
+
+        for &fit in [Fit::Stretch, Fit::Letterbox, Fit::Crop].iter() {
+            let color = color_background(&[255, 0, 0], 1, 1, Format::Rgb, Filter::Bilinear, fit);
+            assert_eq!(&color[160 * 4..161 * 4], &[0, 0, 255, 255]);
+            let mono = mono_background(&[255], 1, 1, Format::Luma, Filter::Lanczos3, fit);
+            assert_eq!(mono[21 * 160 + 80], 255);
+        }
+    }
+
+    #[test]
+    fn fits_extreme_aspect_ratios() {
+        // Too thin to scale down to a full pixel, but never less than one.
+        assert_eq!(letterbox_rect(1000, 1, 320, 240), rect(0, 119, 320, 1));
+        assert_eq!(letterbox_rect(1, 1000, 160, 43), rect(79, 0, 1, 43));
+        assert_eq!(crop_rect(1000, 1, 320, 240), rect(499, 0, 1, 1));
+        assert_eq!(crop_rect(1, 1000, 160, 43), rect(0, 499, 1, 1));
+
+        for &fit in [Fit::Stretch, Fit::Letterbox, Fit::Crop].iter() {
+            let wide = mono_background(&[255; 1000], 1000, 1, Format::Luma, Filter::Bilinear, fit);
+            assert_eq!(wide.len(), 160 * 43);
+            let tall = color_background(&[255; 1000], 1, 1000, Format::Luma, Filter::Nearest, fit);
+            assert_eq!(tall.len(), 320 * 240 * 4);
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! What the driver last sent to the SDK.
+
+use color::Rgb;
+
+/// Counters for the uploads [Driver](struct.Driver.html) sent to the SDK and the ones it
+/// skipped because the content was unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct UploadStats {
+    /// Backgrounds sent, full or partial.
+    pub backgrounds_sent: u64,
+    /// Backgrounds identical to the current one that were not sent.
+    pub backgrounds_skipped: u64,
+    /// Text lines and titles sent.
+    pub texts_sent: u64,
+    /// Text lines and titles identical to the current ones that were not sent.
+    pub texts_skipped: u64,
+}
+
+/// The content of both screens as last sent, `None` where it is unknown.
+#[derive(Debug, Default)]
+pub(crate) struct ScreenState {
+    pub mono_background: Option<Vec<u8>>,
+    pub color_background: Option<Vec<u8>>,
+    pub mono_text: [Option<String>; 4],
+    pub color_title: Option<(String, Rgb)>,
+    pub color_text: [Option<(String, Rgb)>; 8],
+    /// Write positions of the partial background functions. They are only known after a
+    /// reset, the SDK doesn't document where they are after initialization or a full
+    /// background upload.
+    pub mono_partial: Option<usize>,
+    pub color_partial: Option<usize>,
+}
+
+/// Returns `true` if `slot` holds exactly `data`.
+pub(crate) fn is_current(slot: &Option<Vec<u8>>, data: &[u8]) -> bool {
+    slot.as_ref().is_some_and(|current| current[..] == data[..])
+}
+
+/// Replaces the content of `slot`, reusing its allocation.
+pub(crate) fn store(slot: &mut Option<Vec<u8>>, data: &[u8]) {
+    match *slot {
+        Some(ref mut current) if current.len() == data.len() => current.copy_from_slice(data),
+        _ => *slot = Some(data.to_vec()),
+    }
+}
+
+/// Applies a partial background write at `*position` and advances it. This assumes the
+/// SDK copies the data to its write position and advances it by the length written. The
+/// background and the position become unknown if the position was unknown or the write
+/// runs past the end.
+pub(crate) fn store_partial(slot: &mut Option<Vec<u8>>, position: &mut Option<usize>,
+    data: &[u8], size: usize)
+{
+    match position.map(|start| (start, start + data.len())) {
+        Some((start, end)) if end <= size => {
+            if let Some(ref mut current) = *slot {
+                current[start..end].copy_from_slice(data);
+            }
+            *position = Some(end);
+        }
+        _ => {
+            *slot = None;
+            *position = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_current, store, store_partial};
+
+    #[test]
+    fn matching_buffer_is_current() {
+        let mut slot = None;
+        assert!(!is_current(&slot, &[1, 2, 3]));
+        store(&mut slot, &[1, 2, 3]);
+        assert!(is_current(&slot, &[1, 2, 3]));
+        assert!(!is_current(&slot, &[1, 2, 4]));
+        store(&mut slot, &[1, 2, 4]);
+        assert!(is_current(&slot, &[1, 2, 4]));
+    }
+
+    #[test]
+    fn partial_writes_patch_the_shadow() {
+        let mut slot = Some(vec![0; 6]);
+        let mut position = Some(0);
+        store_partial(&mut slot, &mut position, &[1, 2], 6);
+        store_partial(&mut slot, &mut position, &[3, 4, 5], 6);
+        assert_eq!(slot, Some(vec![1, 2, 3, 4, 5, 0]));
+        assert_eq!(position, Some(5));
+        store_partial(&mut slot, &mut position, &[6], 6);
+        assert_eq!(slot, Some(vec![1, 2, 3, 4, 5, 6]));
+        assert_eq!(position, Some(6));
+    }
+
+    #[test]
+    fn partial_write_past_the_end_forgets_the_shadow() {
+        let mut slot = Some(vec![0; 6]);
+        let mut position = Some(4);
+        store_partial(&mut slot, &mut position, &[1, 2, 3], 6);
+        assert_eq!(slot, None);
+        assert_eq!(position, None);
+    }
+
+    #[test]
+    fn partial_write_into_unknown_shadow_stays_unknown() {
+        let mut slot = None;
+        let mut position = Some(0);
+        store_partial(&mut slot, &mut position, &[1, 2, 3, 4, 5, 6], 6);
+        assert_eq!(slot, None);
+        assert_eq!(position, Some(6));
+    }
+
+    #[test]
+    fn partial_write_at_unknown_position_forgets_the_shadow() {
+        let mut slot = Some(vec![0; 6]);
+        let mut position = None;
+        store_partial(&mut slot, &mut position, &[1], 6);
+        assert_eq!(slot, None);
+        assert_eq!(position, None);
+    }
+}
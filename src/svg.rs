@@ -0,0 +1,124 @@
+//! SVG loading and rasterization.
+//!
+//! Requires the `svg` cargo feature, which also enables the `vector` feature.
+//!
+//! An [Svg](struct.Svg.html) document is parsed once with [resvg](https://docs.rs/resvg)
+//! and rasterized at the exact resolution it is needed at, either as a full screen
+//! background for either LCD or into any rectangle of a
+//! [VectorCanvas](../vector/struct.VectorCanvas.html). Monochrome output is composited onto
+//! black and thresholded or dithered. Text elements are not rendered, convert text to
+//! paths when exporting.
+//!
+//! ```
+//! use logitech_lcd::dither::Dither;
+//! use logitech_lcd::resample::Fit;
+//! use logitech_lcd::svg::Svg;
+//!
+//! let icon = Svg::from_data(br#"
+//!     <svg xmlns="http://www.w3.org/2000/svg" width="16" height="16">
+//!         <circle cx="8" cy="8" r="7" fill="white"/>
+//!     </svg>"#).unwrap();
+//!
+//! let mono = icon.mono_background(Fit::Letterbox, Dither::Threshold);
+//! assert_eq!(mono[21 * logitech_lcd::MONO_WIDTH + 80], 255);
+//! assert_eq!(mono[21 * logitech_lcd::MONO_WIDTH + 5], 0);
+//!
+//! let color = icon.color_background(Fit::Stretch);
+//! assert_eq!(color.len(), logitech_lcd::COLOR_WIDTH * logitech_lcd::COLOR_HEIGHT * 4);
+//! ```
+
+extern crate resvg;
+
+use self::resvg::usvg::{Options, Tree};
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use color::Argb;
+use dither::Dither;
+use resample::{Fit, Rect};
+use vector::tiny_skia::{Pixmap, PixmapPaint, Transform};
+use vector::VectorCanvas;
+use Error;
+
+/// A parsed SVG document.
+#[derive(Debug)]
+pub struct Svg {
+    tree: Tree,
+}
+
+impl Svg {
+    /// Parses an SVG document from memory. Compressed SVGZ data is detected and inflated.
+    pub fn from_data(data: &[u8]) -> Result<Svg, Error> {
+        Tree::from_data(data, &Options::default())
+            .map(|tree| Svg { tree })
+            .map_err(|_| Error::Decode("Invalid SVG data."))
+    }
+
+    /// Reads and parses an SVG file.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Svg, Error> {
+        let mut data = Vec::new();
+        File::open(path)
+            .and_then(|mut f| f.read_to_end(&mut data))
+            .map_err(Error::Io)?;
+        Svg::from_data(&data)
+    }
+
+    /// The document's intrinsic size in pixels, from its `width` and `height` or `viewBox`.
+    pub fn size(&self) -> (f32, f32) {
+        let size = self.tree.size();
+        (size.width(), size.height())
+    }
+
+    /// Renders the document into a rectangle of a canvas, composited over its contents.
+    ///
+    /// Parameters:
+    /// - fit: How the document is scaled when its aspect ratio differs from the rectangle's.
+    ///   Letterboxed areas are left untouched, cropped parts are clipped to the rectangle.
+    ///
+    pub fn render(&self, canvas: &mut VectorCanvas, rect: Rect, fit: Fit) {
+        let mut pixmap = match Pixmap::new(rect.width as u32, rect.height as u32) {
+            Some(pixmap) => pixmap,
+            None => return,
+        };
+
+        let (width, height) = self.size();
+        let (sx, sy) = (rect.width as f32 / width, rect.height as f32 / height);
+        let (sx, sy) = match fit {
+            Fit::Stretch => (sx, sy),
+            Fit::Letterbox => (sx.min(sy), sx.min(sy)),
+            Fit::Crop => (sx.max(sy), sx.max(sy)),
+        };
+        let tx = (rect.width as f32 - width * sx) / 2.0;
+        let ty = (rect.height as f32 - height * sy) / 2.0;
+
+        resvg::render(&self.tree, Transform::from_row(sx, 0.0, 0.0, sy, tx, ty),
+            &mut pixmap.as_mut());
+        canvas.pixmap_mut().draw_pixmap(rect.x as i32, rect.y as i32, pixmap.as_ref(),
+            &PixmapPaint::default(), Transform::identity(), None);
+    }
+
+    /// Renders the document as a color background, 320x240 pixels in BGRA order, onto
+    /// opaque black.
+    pub fn color_background(&self, fit: Fit) -> Vec<u8> {
+        let mut canvas = VectorCanvas::color();
+        canvas.clear(Argb::opaque(0, 0, 0));
+        let rect = Rect::new(0, 0, canvas.width(), canvas.height());
+        self.render(&mut canvas, rect, fit);
+        canvas.to_color_background()
+    }
+
+    /// Renders the document as a monochrome background, 160x43 bytes, composited onto
+    /// black.
+    ///
+    /// Parameters:
+    /// - method: How gray levels are reduced to on and off pixels.
+    ///
+    pub fn mono_background(&self, fit: Fit, method: Dither) -> Vec<u8> {
+        let mut canvas = VectorCanvas::mono();
+        let rect = Rect::new(0, 0, canvas.width(), canvas.height());
+        self.render(&mut canvas, rect, fit);
+        canvas.to_mono_background(method)
+    }
+}
@@ -0,0 +1,144 @@
+//! Double buffering.
+//!
+//! A [SwapChain](struct.SwapChain.html) holds a front buffer, the frame currently on the
+//! screen, and a back buffer to render the next frame into. The driver owns one swap chain
+//! per lcd type, rendered into through
+//! [mono_back_buffer](../struct.Driver.html#method.mono_back_buffer) and
+//! [color_back_buffer](../struct.Driver.html#method.color_back_buffer) and shown with
+//! [present](../struct.Driver.html#method.present). Buffers are allocated once and reused,
+//! so presenting a frame doesn't allocate.
+//!
+//! [Driver](../struct.Driver.html) is `Send`, so rendering and presenting can be moved to
+//! a render thread as a whole:
+//!
+//! ```no_run
+//! use logitech_lcd::canvas::Canvas;
+//! use logitech_lcd::color::Argb;
+//! use logitech_lcd::draw::{self, Style};
+//!
+//! let mut driver = logitech_lcd::Driver::init_color("Render thread").unwrap();
+//!
+//! let render = std::thread::spawn(move || {
+//!     for frame in 0..600 {
+//!         {
+//!             let back = driver.color_back_buffer();
+//!             back.clear(Argb::opaque(0, 0, 0));
+//!             draw::circle(back, frame % 320, 120, 20, &Style::fill(Argb::opaque(255, 0, 0)));
+//!         }
+//!         driver.present().unwrap();
+//!         std::thread::sleep(std::time::Duration::from_millis(16));
+//!     }
+//! });
+//! render.join().unwrap();
+//! ```
+
+use std::mem;
+
+/// A front buffer and a back buffer.
+#[derive(Debug, Clone)]
+pub struct SwapChain<F> {
+    front: F,
+    back: F,
+    touched: bool,
+}
+
+impl<F> SwapChain<F> {
+    /// Creates a swap chain from two buffers.
+    pub fn new(front: F, back: F) -> SwapChain<F> {
+        SwapChain { front, back, touched: false }
+    }
+
+    /// The buffer last presented.
+    pub fn front(&self) -> &F {
+        &self.front
+    }
+
+    /// The buffer the next frame is rendered into. After a swap it holds the frame before
+    /// the one on the screen, not a copy of the front buffer.
+    pub fn back(&self) -> &F {
+        &self.back
+    }
+
+    /// The buffer the next frame is rendered into, see [back](#method.back). Marks the
+    /// chain as touched, so the next [present](#method.present) uploads it.
+    pub fn back_mut(&mut self) -> &mut F {
+        self.touched = true;
+        &mut self.back
+    }
+
+    /// Returns `true` if the back buffer was borrowed mutably since the last swap.
+    pub fn is_touched(&self) -> bool {
+        self.touched
+    }
+
+    /// Exchanges the front and back buffers, without copying their contents, and clears
+    /// the touched flag.
+    pub fn swap(&mut self) {
+        mem::swap(&mut self.front, &mut self.back);
+        self.touched = false;
+    }
+
+    /// Uploads the back buffer with `flush` and swaps if the upload succeeded. Does
+    /// nothing if the chain was not [touched](#method.is_touched) since the last swap, so
+    /// a stale back buffer is never shown.
+    ///
+    /// Return value:
+    /// `true` if the back buffer was uploaded and swapped, or the error of `flush`.
+    ///
+    pub fn present<E, U>(&mut self, flush: U) -> Result<bool, E>
+        where U: FnOnce(&mut F) -> Result<(), E>
+    {
+        if !self.touched {
+            return Ok(false);
+        }
+        flush(&mut self.back)?;
+        self.swap();
+        Ok(true)
+    }
+}
+
+impl<F: Clone> SwapChain<F> {
+    /// Copies the front buffer into the back buffer, for renderers that only draw what
+    /// changed since the last frame.
+    pub fn copy_front_to_back(&mut self) {
+        self.back.clone_from(&self.front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SwapChain;
+
+    #[test]
+    fn present_skips_untouched_chains() {
+        let mut mono = SwapChain::new(0, 0);
+        let mut color = SwapChain::new(0, 0);
+        let mut uploads = Vec::new();
+
+        for frame in 1..3 {
+            *color.back_mut() = frame;
+            let mono_sent = mono.present(|b| {
+                uploads.push(("mono", *b));
+                Ok::<(), ()>(())
+            });
+            let color_sent = color.present(|b| {
+                uploads.push(("color", *b));
+                Ok::<(), ()>(())
+            });
+            assert_eq!((mono_sent, color_sent), (Ok(false), Ok(true)));
+        }
+        assert_eq!(uploads, [("color", 1), ("color", 2)]);
+        assert_eq!((*color.front(), *color.back()), (2, 1));
+    }
+
+    #[test]
+    fn failed_present_keeps_chain_touched() {
+        let mut chain = SwapChain::new(0, 0);
+        *chain.back_mut() = 1;
+        assert_eq!(chain.present(|_| Err("upload failed")), Err("upload failed"));
+        assert!(chain.is_touched());
+        assert_eq!(*chain.front(), 0);
+        assert_eq!(chain.present(|_| Ok::<(), ()>(())), Ok(true));
+        assert_eq!(*chain.front(), 1);
+    }
+}
@@ -0,0 +1,269 @@
+//! Column layout for the SDK's text lines.
+//!
+//! Text lines use a fixed-width font, so columns line up when every cell is padded to
+//! the width of its column. A [Table](struct.Table.html) does that from a list of
+//! [Column](struct.Column.html) specs and writes the rows to the text lines of either lcd,
+//! with an optional header on the color title. The title uses a bigger font, so
+//! [format_title](struct.Table.html#method.format_title) narrows the columns to fit it.
+//!
+//! ```
+//! use logitech_lcd::font::Align;
+//! use logitech_lcd::table::{Column, Overflow, Table};
+//! use logitech_lcd::text::LineCapacity;
+//!
+//! let table = Table::new(vec![
+//!     Column::new(10),
+//!     Column::new(6).align(Align::Right),
+//!     Column::new(6).align(Align::Right).overflow(Overflow::Clip),
+//! ]).header(&["Process", "CPU", "Memory"]);
+//!
+//! assert_eq!(table.format_row(&["chrome.exe", "12.5%", "1.2 GB"]), "chrome.exe  12.5% 1.2 GB");
+//! assert_eq!(table.format_row(&["explorer.exe", "0.3%"]), "explore...   0.3%       ");
+//! let title = table.format_title(&["Process", "CPU", "Memory"], &LineCapacity::default());
+//! assert_eq!(title, "Process   CPU Memo");
+//! ```
+
+use color::Rgb;
+use font::Align;
+use text::{self, LineCapacity};
+use {Driver, Error};
+
+/// What happens to cells longer than their column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Cut the cell and end it with an ellipsis.
+    Truncate,
+    /// Cut the cell at the column width.
+    Clip,
+}
+
+/// The layout of one column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Column {
+    width: usize,
+    align: Align,
+    overflow: Overflow,
+}
+
+impl Column {
+    /// Creates a left aligned column that truncates long cells with an ellipsis.
+    pub fn new(width: usize) -> Column {
+        Column { width, align: Align::Left, overflow: Overflow::Truncate }
+    }
+
+    /// Changes the alignment of the cells within the column.
+    pub fn align(mut self, align: Align) -> Column {
+        self.align = align;
+        self
+    }
+
+    /// Changes what happens to cells longer than the column.
+    pub fn overflow(mut self, overflow: Overflow) -> Column {
+        self.overflow = overflow;
+        self
+    }
+
+    fn format(&self, cell: &str) -> String {
+        match self.overflow {
+            Overflow::Truncate => text::fit(cell, self.width, self.align),
+            Overflow::Clip => {
+                let clipped: String = cell.chars().take(self.width).collect();
+                text::pad(&clipped, self.width, self.align)
+            },
+        }
+    }
+}
+
+/// Columns, a separator and an optional header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    columns: Vec<Column>,
+    separator: String,
+    header: Option<Vec<String>>,
+}
+
+impl Table {
+    /// Creates a table with columns separated by a single space.
+    pub fn new(columns: Vec<Column>) -> Table {
+        Table { columns, separator: " ".to_owned(), header: None }
+    }
+
+    /// Changes the text between columns.
+    pub fn separator(mut self, separator: &str) -> Table {
+        self.separator = separator.to_owned();
+        self
+    }
+
+    /// Sets a header row, formatted like any other row.
+    pub fn header<S: AsRef<str>>(mut self, cells: &[S]) -> Table {
+        self.header = Some(cells.iter().map(|c| c.as_ref().to_owned()).collect());
+        self
+    }
+
+    /// Total width of a formatted row in characters.
+    pub fn width(&self) -> usize {
+        let columns: usize = self.columns.iter().map(|c| c.width).sum();
+        let separators = self.columns.len().saturating_sub(1) * self.separator.chars().count();
+        columns + separators
+    }
+
+    /// Formats one row. Missing cells are left blank and cells beyond the last column are
+    /// ignored.
+    pub fn format_row<S: AsRef<str>>(&self, cells: &[S]) -> String {
+        let mut row = String::with_capacity(self.width());
+        for (i, column) in self.columns.iter().enumerate() {
+            if i > 0 {
+                row.push_str(&self.separator);
+            }
+            let cell = cells.get(i).map(|c| c.as_ref()).unwrap_or("");
+            row.push_str(&column.format(cell));
+        }
+        row
+    }
+
+    /// Formats one row for the color title, like [format_row](#method.format_row).
+    ///
+    /// The title fits fewer characters than a color text line, see
+    /// [LineCapacity](../text/struct.LineCapacity.html), so column and separator widths are
+    /// scaled by that ratio to put each cell roughly above its column on the color text
+    /// lines. Separators are cut to the scaled space between columns.
+    ///
+    /// Panics:
+    /// - If capacity.color_line is 0.
+    ///
+    pub fn format_title<S: AsRef<str>>(&self, cells: &[S], capacity: &LineCapacity)
+        -> String
+    {
+        assert!(capacity.color_line > 0);
+        let scale = |chars: usize| {
+            (chars * capacity.color_title + capacity.color_line / 2) / capacity.color_line
+        };
+        let separator_len = self.separator.chars().count();
+
+        let mut row = String::with_capacity(scale(self.width()));
+        let mut end = 0;
+        for (i, column) in self.columns.iter().enumerate() {
+            let mut start = end;
+            if i > 0 {
+                start += separator_len;
+                let gap = scale(start) - scale(end);
+                let separator: String = self.separator.chars().take(gap).collect();
+                row.push_str(&text::pad(&separator, gap, Align::Left));
+            }
+            end = start + column.width;
+
+            let cell = cells.get(i).map(|c| c.as_ref()).unwrap_or("");
+            let column = Column { width: scale(end) - scale(start), ..*column };
+            row.push_str(&column.format(cell));
+        }
+        row
+    }
+
+    /// Writes the table to the monochrome text lines. The header, if any, takes the first
+    /// line, and lines not used by the table are cleared.
+    ///
+    /// Return value:
+    /// The number of rows shown, not counting the header. The remaining rows didn't fit.
+    ///
+    /// Panics:
+    /// - If Lcd was initialized without mono support.
+    ///
+    pub fn write_mono<R, S>(&self, driver: &mut Driver, rows: &[R]) -> Result<usize, Error>
+        where R: AsRef<[S]>, S: AsRef<str>
+    {
+        let mut lines: Vec<String> = Vec::with_capacity(4);
+        if let Some(ref header) = self.header {
+            lines.push(self.format_row(header));
+        }
+        let shown = rows.len().min(4 - lines.len());
+        lines.extend(rows[..shown].iter().map(|row| self.format_row(row.as_ref())));
+
+        for line_number in 0..4 {
+            driver.set_mono_text(line_number, lines.get(line_number).map_or("", |l| &l[..]))?;
+        }
+        Ok(shown)
+    }
+
+    /// Writes the table to the color text lines in one color. The header, if any, is
+    /// written to the title with [format_title](#method.format_title), otherwise the title
+    /// is cleared, as are lines not used by the table.
+    ///
+    /// Return value:
+    /// The number of rows shown. The remaining rows didn't fit.
+    ///
+    /// Panics:
+    /// - If Lcd was initialized without color support.
+    ///
+    pub fn write_color<R, S>(&self, driver: &mut Driver, rows: &[R], color: Rgb)
+        -> Result<usize, Error>
+        where R: AsRef<[S]>, S: AsRef<str>
+    {
+        let capacity = driver.line_capacity();
+        let title = self.header.as_ref()
+            .map_or(String::new(), |h| self.format_title(h, &capacity));
+        driver.set_color_title(&title, color.r, color.g, color.b)?;
+
+        for line_number in 0..8 {
+            let line = rows.get(line_number)
+                .map_or(String::new(), |row| self.format_row(row.as_ref()));
+            driver.set_color_text(line_number, &line, color.r, color.g, color.b)?;
+        }
+        Ok(rows.len().min(8))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font::Align;
+    use text::LineCapacity;
+    use super::{Column, Overflow, Table};
+
+    fn capacity(color_title: usize, color_line: usize) -> LineCapacity {
+        LineCapacity { mono_line: 26, color_title, color_line }
+    }
+
+    #[test]
+    fn formats_missing_and_extra_cells() {
+        let table = Table::new(vec![Column::new(3), Column::new(3).align(Align::Right)]);
+        assert_eq!(table.format_row(&["a"]), "a      ");
+        assert_eq!(table.format_row::<&str>(&[]), "       ");
+        assert_eq!(table.format_row(&["a", "b", "c", "d"]), "a     b");
+        assert_eq!(table.width(), 7);
+    }
+
+    #[test]
+    fn formats_multi_char_separators() {
+        let table = Table::new(vec![Column::new(2), Column::new(2), Column::new(2)])
+            .separator(" | ");
+        assert_eq!(table.width(), 12);
+        assert_eq!(table.format_row(&["a", "b", "c"]), "a  | b  | c ");
+        assert_eq!(table.format_title(&["a", "b", "c"], &capacity(12, 12)), "a  | b  | c ");
+        // At half the width the separators are cut to the 2 and 1 characters left for them.
+        assert_eq!(table.format_title(&["a", "b", "c"], &capacity(1, 2)), "a |b c");
+    }
+
+    #[test]
+    fn title_columns_can_scale_to_nothing() {
+        let table = Table::new(vec![Column::new(1), Column::new(1), Column::new(8)]);
+        // The first two columns and the second separator scale to nothing.
+        assert_eq!(table.format_title(&["x", "y", "z"], &capacity(1, 4)), " z ");
+        assert_eq!(table.format_title(&["x", "y", "z"], &capacity(0, 4)), "");
+    }
+
+    #[test]
+    fn title_respects_overflow() {
+        let table = Table::new(vec![
+            Column::new(10),
+            Column::new(10).overflow(Overflow::Clip),
+        ]);
+        let title = table.format_title(&["Temperature", "Temperature"], &capacity(1, 2));
+        assert_eq!(title, "Te... Tempe");
+    }
+
+    #[test]
+    fn title_matches_row_at_equal_capacity() {
+        let table = Table::new(vec![Column::new(5), Column::new(4).align(Align::Center)]);
+        let cells = ["abc", "de"];
+        assert_eq!(table.format_title(&cells, &capacity(26, 26)), table.format_row(&cells));
+    }
+}
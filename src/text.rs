@@ -0,0 +1,306 @@
+//! Fitting text to the SDK's text lines.
+//!
+//! The SDK renders text lines in a fixed-width font and clips whatever doesn't fit.
+//! [LineCapacity](struct.LineCapacity.html) holds the number of characters that fit on
+//! each kind of line, and [fit](fn.fit.html) truncates text with an ellipsis and pads it
+//! to align it within a line.
+//! [Driver::set_mono_text_aligned](../struct.Driver.html#method.set_mono_text_aligned)
+//! and [Driver::set_color_text_aligned](../struct.Driver.html#method.set_color_text_aligned)
+//! apply it to a line directly, with the capacity set on the driver. [wrap](fn.wrap.html)
+//! breaks longer text into lines for
+//! [Driver::set_mono_paragraph](../struct.Driver.html#method.set_mono_paragraph) and
+//! [Driver::set_color_paragraph](../struct.Driver.html#method.set_color_paragraph).
+//!
+//! [LineRects](struct.LineRects.html) holds the pixels the SDK draws each line at, for
+//! drawing behind it, see [draw::highlight_line](../draw/fn.highlight_line.html) and
+//! [draw::invert_line](../draw/fn.invert_line.html). The SDK doesn't document its layout,
+//! so the default rects are a guess and can be replaced with measured ones.
+//!
+//! ```
+//! use logitech_lcd::font::Align;
+//! use logitech_lcd::text::{fit, truncate, wrap};
+//!
+//! assert_eq!(truncate("Temperature", 8), "Tempe...");
+//! assert_eq!(fit("42%", 7, Align::Right), "    42%");
+//! assert_eq!(fit("CPU", 7, Align::Center), "  CPU  ");
+//!
+//! let text = "Press any key to continue\nor wait";
+//! assert_eq!(wrap(text, 10), ["Press any", "key to", "continue", "or wait"]);
+//! ```
+
+use std::borrow::Cow;
+
+use canvas::Rect;
+use font::Align;
+use {COLOR_WIDTH, MONO_WIDTH};
+
+/// Number of characters that fit on each kind of text line.
+///
+/// The SDK doesn't document the font it draws text lines with, and the defaults are
+/// estimates that were not measured on hardware. Applications that know better, or that
+/// must never have a line clipped, set their own values with
+/// [Driver::set_line_capacity](../struct.Driver.html#method.set_line_capacity).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LineCapacity {
+    /// Characters on a monochrome text line, 26 by default.
+    pub mono_line: usize,
+    /// Characters on the color title, which uses a bigger font, 20 by default.
+    pub color_title: usize,
+    /// Characters on a color text line, 26 by default.
+    pub color_line: usize,
+}
+
+impl Default for LineCapacity {
+    fn default() -> LineCapacity {
+        LineCapacity { mono_line: 26, color_title: 20, color_line: 26 }
+    }
+}
+
+impl LineCapacity {
+    /// Characters that fit on a line.
+    pub fn chars(&self, line: TextLine) -> usize {
+        match line {
+            TextLine::Mono(_) => self.mono_line,
+            TextLine::ColorTitle => self.color_title,
+            TextLine::Color(_) => self.color_line,
+        }
+    }
+}
+
+/// A text line of either lcd.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextLine {
+    /// A monochrome line, 0 to 3.
+    Mono(usize),
+    /// The color title.
+    ColorTitle,
+    /// A color line below the title, 0 to 7.
+    Color(usize),
+}
+
+/// Where the SDK draws its text lines, in pixels.
+///
+/// The SDK doesn't document this. The defaults split each screen evenly between its lines,
+/// with a 32 pixel title on the color screen. They are a guess, not measured on hardware,
+/// so a highlight bar may be a few pixels off the text in front of it. Applications that
+/// need exact bars measure the lines on their device and set their own rects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LineRects {
+    /// The 4 monochrome lines.
+    pub mono: [Rect; 4],
+    /// The color title.
+    pub color_title: Rect,
+    /// The 8 color lines below the title.
+    pub color: [Rect; 8],
+}
+
+impl Default for LineRects {
+    fn default() -> LineRects {
+        let mut mono = [Rect::new(0, 0, MONO_WIDTH, 11); 4];
+        for (line, rect) in mono.iter_mut().enumerate() {
+            rect.y = line * 11;
+        }
+        // The mono screen is 43 pixels high, one short of 4 x 11.
+        mono[3].height = 10;
+
+        let mut color = [Rect::new(0, 0, COLOR_WIDTH, 26); 8];
+        for (line, rect) in color.iter_mut().enumerate() {
+            rect.y = 32 + line * 26;
+        }
+        LineRects { mono, color_title: Rect::new(0, 0, COLOR_WIDTH, 32), color }
+    }
+}
+
+impl LineRects {
+    /// The rect of a line.
+    ///
+    /// Panics:
+    /// - If a monochrome line is larger than 3 or a color line larger than 7.
+    ///
+    pub fn rect(&self, line: TextLine) -> Rect {
+        match line {
+            TextLine::Mono(line) => self.mono[line],
+            TextLine::ColorTitle => self.color_title,
+            TextLine::Color(line) => self.color[line],
+        }
+    }
+}
+
+
+/// Appended to truncated text.
+pub const ELLIPSIS: &str = "...";
+
+/// Shortens text to at most `width` characters, replacing the end with
+/// [ELLIPSIS](constant.ELLIPSIS.html) if anything was cut. Text that fits is returned
+/// unchanged.
+pub fn truncate(text: &str, width: usize) -> Cow<'_, str> {
+    if text.chars().count() <= width {
+        return Cow::Borrowed(text);
+    }
+
+    let ellipsis_len = ELLIPSIS.chars().count();
+    if width <= ellipsis_len {
+        return Cow::Owned(ELLIPSIS.chars().take(width).collect());
+    }
+
+    let mut out: String = text.chars().take(width - ellipsis_len).collect();
+    out.push_str(ELLIPSIS);
+    Cow::Owned(out)
+}
+
+/// Pads text with spaces to exactly `width` characters, aligned within that width.
+/// Text that is too long is returned unchanged.
+pub fn pad(text: &str, width: usize, align: Align) -> String {
+    let len = text.chars().count();
+    let space = width.saturating_sub(len);
+    let left = match align {
+        Align::Left => 0,
+        Align::Center => space / 2,
+        Align::Right => space,
+    };
+
+    let mut out = String::with_capacity(text.len() + space);
+    out.extend(std::iter::repeat_n(' ', left));
+    out.push_str(text);
+    out.extend(std::iter::repeat_n(' ', space - left));
+    out
+}
+
+/// Truncates and pads text to exactly `width` characters, see [truncate](fn.truncate.html)
+/// and [pad](fn.pad.html).
+pub fn fit(text: &str, width: usize, align: Align) -> String {
+    pad(&truncate(text, width), width, align)
+}
+
+/// Word-wraps text into lines of at most `width` characters.
+///
+/// Lines break at spaces, and words longer than a line are split. Explicit newlines start
+/// a new line, so empty lines are kept. The spaces a line is broken at are dropped. The
+/// returned lines are slices of `text`, so the position of a line in `text` can be used
+/// to continue from it, see [remainder](fn.remainder.html).
+///
+/// Panics:
+/// - If width is 0.
+///
+pub fn wrap(text: &str, width: usize) -> Vec<&str> {
+    assert!(width > 0);
+    let mut lines = Vec::new();
+
+    for mut rest in text.lines() {
+        loop {
+            let end = rest.char_indices().nth(width).map(|(i, _)| i);
+            let end = match end {
+                Some(end) => end,
+                None => {
+                    lines.push(rest.trim_end());
+                    break;
+                },
+            };
+
+            // Break at the last space within the first width + 1 characters, or split the
+            // word if there is none after the indentation.
+            let space = match rest[end..].starts_with(' ') {
+                true => Some(end),
+                false => rest[..end].rfind(' '),
+            };
+            let split = match space {
+                Some(space) if !rest[..space].trim().is_empty() => space,
+                _ => end,
+            };
+            let (line, next) = rest.split_at(split);
+            lines.push(line.trim_end());
+            rest = next.trim_start_matches(' ');
+            if rest.is_empty() {
+                break;
+            }
+        }
+    }
+    lines
+}
+
+/// The part of `text` starting at `line`, which must be one of the lines returned by
+/// [wrap](fn.wrap.html) for `text`.
+///
+/// Panics:
+/// - If line is not a slice of text.
+///
+pub fn remainder<'t>(text: &'t str, line: &str) -> &'t str {
+    let start = (line.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+    assert!(start <= text.len());
+    &text[start..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{remainder, wrap, LineRects, TextLine};
+    use {COLOR_HEIGHT, MONO_HEIGHT};
+
+    #[test]
+    fn wraps_at_spaces() {
+        assert_eq!(wrap("the quick brown fox", 10), ["the quick", "brown fox"]);
+        assert_eq!(wrap("short", 10), ["short"]);
+        assert_eq!(wrap("", 10), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn breaks_at_a_space_exactly_at_the_width() {
+        assert_eq!(wrap("abcde fghij", 5), ["abcde", "fghij"]);
+        assert_eq!(wrap("abcd efghij", 5), ["abcd", "efghi", "j"]);
+    }
+
+    #[test]
+    fn splits_words_longer_than_a_line() {
+        assert_eq!(wrap("abcdefghijkl", 5), ["abcde", "fghij", "kl"]);
+        assert_eq!(wrap("ab cdefghijkl", 5), ["ab", "cdefg", "hijkl"]);
+    }
+
+    #[test]
+    fn keeps_indentation() {
+        assert_eq!(wrap("  indented text", 10), ["  indented", "text"]);
+        assert_eq!(wrap("    abcdefgh", 6), ["    ab", "cdefgh"]);
+    }
+
+    #[test]
+    fn keeps_empty_lines() {
+        assert_eq!(wrap("one\n\ntwo", 10), ["one", "", "two"]);
+        assert_eq!(wrap("one\n", 10), ["one"]);
+    }
+
+    #[test]
+    fn counts_characters_not_bytes() {
+        assert_eq!(wrap("\u{e9}t\u{e9} \u{e0} la plage", 5),
+            ["\u{e9}t\u{e9} \u{e0}", "la", "plage"]);
+        assert_eq!(wrap("\u{65e5}\u{672c}\u{8a9e}\u{65e5}\u{672c}", 2),
+            ["\u{65e5}\u{672c}", "\u{8a9e}\u{65e5}", "\u{672c}"]);
+    }
+
+    #[test]
+    fn remainder_continues_at_a_line() {
+        let text = "one two three four five six seven eight nine ten";
+        let lines = wrap(text, 9);
+        assert_eq!(lines, ["one two", "three", "four five", "six seven", "eight", "nine ten"]);
+        assert_eq!(remainder(text, lines[4]), "eight nine ten");
+        assert_eq!(remainder(text, lines[0]), text);
+
+        let text = "first\n\nthird line\nfourth";
+        let lines = wrap(text, 10);
+        assert_eq!(remainder(text, lines[1]), "\nthird line\nfourth");
+        assert_eq!(remainder(text, lines[3]), "fourth");
+    }
+
+    #[test]
+    fn default_rects_split_the_screens() {
+        let rects = LineRects::default();
+        let mono: Vec<_> = (0..4).map(|line| rects.rect(TextLine::Mono(line))).collect();
+        let color: Vec<_> = Some(TextLine::ColorTitle).into_iter()
+            .chain((0..8).map(TextLine::Color))
+            .map(|line| rects.rect(line))
+            .collect();
+        for (lines, height) in [(mono, MONO_HEIGHT), (color, COLOR_HEIGHT)] {
+            assert_eq!(lines[0].y, 0);
+            assert!(lines.windows(2).all(|w| w[0].y + w[0].height == w[1].y));
+            let last = lines[lines.len() - 1];
+            assert_eq!(last.y + last.height, height);
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Antialiased TrueType and OpenType text rendering.
+//!
+//! Requires the `truetype` cargo feature.
+//!
+//! A [FontStack](struct.FontStack.html) renders text with a primary [Font](struct.Font.html)
+//! and falls back to the next font in the stack for characters the primary font doesn't
+//! cover. Glyphs are positioned with subpixel precision and kerned with the font's `kern`
+//! table, then composited with their coverage onto any
+//! [Canvas](../canvas/trait.Canvas.html). On a [ColorCanvas](../canvas/struct.ColorCanvas.html)
+//! this gives smooth edges and honors the alpha of the text color, on monochrome canvases
+//! the coverage is thresholded.
+//!
+//! ```no_run
+//! use logitech_lcd::canvas::ColorCanvas;
+//! use logitech_lcd::color::Argb;
+//! use logitech_lcd::font::Align;
+//! use logitech_lcd::truetype::{Font, FontStack};
+//! use logitech_lcd::{COLOR_WIDTH, COLOR_HEIGHT};
+//!
+//! let fonts = FontStack::new(Font::open("brand.ttf").unwrap(), 32.0)
+//!     .fallback(Font::open("symbols.otf").unwrap());
+//!
+//! let mut background = vec![0u8; COLOR_WIDTH * COLOR_HEIGHT * 4];
+//! fonts.draw(&mut ColorCanvas::new(&mut background), "Score: 1 337 ★", 160.0, 20.0,
+//!     Align::Center, Argb::new(200, 255, 255, 255));
+//!
+//! let mut driver = logitech_lcd::Driver::init_color("TrueType").unwrap();
+//! driver.set_color_background(&background).unwrap();
+//! driver.update();
+//! ```
+
+extern crate ab_glyph;
+
+use self::ab_glyph::{Font as AbFont, FontArc, Glyph, GlyphId, PxScale, ScaleFont};
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use canvas::Canvas;
+use font::Align;
+use Error;
+
+/// A parsed TrueType or OpenType font.
+#[derive(Clone, Debug)]
+pub struct Font {
+    inner: FontArc,
+}
+
+impl Font {
+    /// Parses a font from memory.
+    pub fn from_bytes(data: Vec<u8>) -> Result<Font, Error> {
+        FontArc::try_from_vec(data)
+            .map(|inner| Font { inner })
+            .map_err(|_| Error::Decode("Invalid font data."))
+    }
+
+    /// Parses a font embedded in the binary, without copying it.
+    pub fn from_static(data: &'static [u8]) -> Result<Font, Error> {
+        FontArc::try_from_slice(data)
+            .map(|inner| Font { inner })
+            .map_err(|_| Error::Decode("Invalid font data."))
+    }
+
+    /// Reads and parses a font file.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Font, Error> {
+        let mut data = Vec::new();
+        File::open(path)
+            .and_then(|mut f| f.read_to_end(&mut data))
+            .map_err(Error::Io)?;
+        Font::from_bytes(data)
+    }
+
+    /// Returns `true` if the font has a glyph for the character.
+    pub fn has_glyph(&self, c: char) -> bool {
+        self.inner.glyph_id(c).0 != 0
+    }
+}
+
+/// A glyph placed on a line, before the line is aligned.
+struct Placed {
+    font: usize,
+    glyph: Glyph,
+}
+
+/// A primary font with a chain of fallback fonts, at a given size.
+#[derive(Clone, Debug)]
+pub struct FontStack {
+    fonts: Vec<Font>,
+    size: f32,
+}
+
+impl FontStack {
+    /// Creates a stack with a single font.
+    ///
+    /// Parameters:
+    /// - size: Font size in pixels, the distance from the ascender to the descender.
+    ///
+    pub fn new(primary: Font, size: f32) -> FontStack {
+        FontStack { fonts: vec![primary], size }
+    }
+
+    /// Font size in pixels.
+    pub fn size(&self) -> f32 {
+        self.size
+    }
+
+    /// Changes the font size. Fonts are reference counted, so stacks at different sizes
+    /// can be cloned cheaply.
+    pub fn set_size(&mut self, size: f32) {
+        self.size = size;
+    }
+
+    /// Appends a fallback font, used for characters missing from all earlier fonts.
+    pub fn fallback(mut self, font: Font) -> FontStack {
+        self.fonts.push(font);
+        self
+    }
+
+    /// Distance in pixels between the baselines of two lines, from the primary font.
+    pub fn line_height(&self) -> f32 {
+        let font = self.fonts[0].inner.as_scaled(PxScale::from(self.size));
+        font.height() + font.line_gap()
+    }
+
+    /// Width in pixels of the widest line of `text`.
+    pub fn measure(&self, text: &str) -> f32 {
+        text.lines()
+            .map(|line| self.layout_line(line).1)
+            .fold(0.0, f32::max)
+    }
+
+    /// Draws antialiased text onto a canvas.
+    ///
+    /// Lines are separated by `'\n'` and aligned individually. Everything outside the
+    /// canvas' clip rect is clipped.
+    ///
+    /// Parameters:
+    /// - x: Horizontal anchor of each line, see [Align](../font/enum.Align.html).
+    /// - y: Top of the first line.
+    /// - color: Pixel value for the text, composited with the glyph coverage.
+    ///
+    /// Return value:
+    /// The width in pixels of the widest line, same as [measure](#method.measure).
+    ///
+    pub fn draw<C: Canvas>(&self, canvas: &mut C, text: &str, x: f32, y: f32, align: Align,
+        color: C::Pixel) -> f32
+    {
+        let ascent = self.fonts[0].inner.as_scaled(PxScale::from(self.size)).ascent();
+        let line_height = self.line_height();
+        let mut widest = 0.0f32;
+
+        for (line_index, line) in text.lines().enumerate() {
+            let (glyphs, width) = self.layout_line(line);
+            widest = widest.max(width);
+
+            let left = match align {
+                Align::Left => x,
+                Align::Center => x - width / 2.0,
+                Align::Right => x - width,
+            };
+            let baseline = y + ascent + line_index as f32 * line_height;
+
+            for placed in glyphs {
+                let mut glyph = placed.glyph;
+                glyph.position.x += left;
+                glyph.position.y = baseline;
+
+                if let Some(outline) = self.fonts[placed.font].inner.outline_glyph(glyph) {
+                    let bounds = outline.px_bounds();
+                    let (ox, oy) = (bounds.min.x as isize, bounds.min.y as isize);
+                    outline.draw(|gx, gy, coverage| {
+                        let coverage = (coverage * 255.0).round().clamp(0.0, 255.0) as u8;
+                        if coverage > 0 {
+                            canvas.put_blended(ox + gx as isize, oy + gy as isize, color,
+                                coverage);
+                        }
+                    });
+                }
+            }
+        }
+
+        widest
+    }
+
+    /// Picks the first font that covers the character, the primary font's missing glyph
+    /// if none does.
+    fn resolve(&self, c: char) -> (usize, GlyphId) {
+        for (i, font) in self.fonts.iter().enumerate() {
+            let id = font.inner.glyph_id(c);
+            if id.0 != 0 {
+                return (i, id);
+            }
+        }
+        (0, GlyphId(0))
+    }
+
+    /// Places the glyphs of a line starting at x = 0 and returns them with the line width.
+    fn layout_line(&self, line: &str) -> (Vec<Placed>, f32) {
+        let scale = PxScale::from(self.size);
+        let mut glyphs = Vec::new();
+        let mut caret = 0.0f32;
+        let mut previous: Option<(usize, GlyphId)> = None;
+
+        for c in line.chars() {
+            let (font_index, id) = self.resolve(c);
+            let font = self.fonts[font_index].inner.as_scaled(scale);
+
+            // Kerning only applies between glyphs from the same font.
+            if let Some((prev_font, prev_id)) = previous {
+                if prev_font == font_index {
+                    caret += font.kern(prev_id, id);
+                }
+            }
+
+            glyphs.push(Placed {
+                font: font_index,
+                glyph: id.with_scale_and_position(scale, ab_glyph::point(caret, 0.0)),
+            });
+            caret += font.h_advance(id);
+            previous = Some((font_index, id));
+        }
+
+        (glyphs, caret)
+    }
+}
@@ -0,0 +1,329 @@
+//! Cleaning up text for the SDK's text lines.
+//!
+//! Requires the `unicode` cargo feature.
+//!
+//! The SDK renders text lines in a fixed-width font with a limited character set. Tabs and
+//! other control characters, combining marks and characters outside the font are sent as
+//! is and show up as garbage, and wide CJK characters take two columns, which breaks the
+//! alignment of [text::fit](../text/fn.fit.html). A [Sanitizer](struct.Sanitizer.html)
+//! turns arbitrary text into something the font can show:
+//!
+//! 1. The text is normalized to NFC, so accented letters become single characters.
+//! 2. Control and format characters are removed. Tabs and newlines are kept, `\r\n` becomes
+//!    `\n`.
+//! 3. Characters outside the [Charset](enum.Charset.html) are transliterated to ASCII,
+//!    `"Ω"` to `"O"` and `"北京"` to `"Bei Jing"`, or replaced if there is no transliteration.
+//! 4. Tabs are expanded to spaces up to the next tab stop.
+//!
+//! [width](fn.width.html), [truncate](fn.truncate.html), [pad](fn.pad.html) and
+//! [fit](fn.fit.html) are the counterparts of the functions in [text](../text/index.html)
+//! that count columns instead of characters, so wide characters kept with
+//! [Charset::Unicode](enum.Charset.html#variant.Unicode) line up.
+//!
+//! ```
+//! use logitech_lcd::font::Align;
+//! use logitech_lcd::unicode::{self, Charset, Sanitizer};
+//!
+//! let sanitizer = Sanitizer::new();
+//! assert_eq!(sanitizer.sanitize("Cafe\u{301}\tΩ\u{7}"), "Café    O");
+//! assert_eq!(sanitizer.sanitize("北京"), "Bei Jing");
+//!
+//! let wide = Sanitizer::new().charset(Charset::Unicode);
+//! assert_eq!(wide.fit("北京 ok", 8, Align::Right), " 北京 ok");
+//! assert_eq!(unicode::width("北京 ok"), 7);
+//! ```
+
+extern crate deunicode;
+extern crate unicode_normalization;
+extern crate unicode_width;
+
+use self::unicode_normalization::UnicodeNormalization;
+use self::unicode_width::UnicodeWidthChar;
+
+use std::borrow::Cow;
+
+use font::Align;
+use text::ELLIPSIS;
+
+/// The characters a sanitized text may contain. Others are transliterated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Charset {
+    /// Printable ASCII.
+    Ascii,
+    /// Printable ASCII and the printable characters of Latin-1, U+00A0 to U+00FF.
+    Latin1,
+    /// Any printable character, nothing is transliterated.
+    Unicode,
+}
+
+impl Charset {
+    /// Returns `true` if the character set includes `c`.
+    pub fn contains(self, c: char) -> bool {
+        match self {
+            Charset::Ascii => (' '..='~').contains(&c),
+            Charset::Latin1 => (' '..='~').contains(&c) || ('\u{a0}'..='\u{ff}').contains(&c),
+            Charset::Unicode => true,
+        }
+    }
+}
+
+/// Settings of the sanitization pipeline, see the [module documentation](index.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sanitizer {
+    charset: Charset,
+    tab_width: usize,
+    replacement: String,
+}
+
+impl Sanitizer {
+    /// Creates a sanitizer for [Charset::Latin1](enum.Charset.html#variant.Latin1) with tab
+    /// stops every 4 columns that replaces characters without a transliteration with `?`.
+    pub fn new() -> Sanitizer {
+        Sanitizer { charset: Charset::Latin1, tab_width: 4, replacement: "?".to_owned() }
+    }
+
+    /// Changes the characters that are kept as is.
+    pub fn charset(mut self, charset: Charset) -> Sanitizer {
+        self.charset = charset;
+        self
+    }
+
+    /// Changes the distance between tab stops.
+    ///
+    /// Panics:
+    /// - If tab_width is 0.
+    ///
+    pub fn tab_width(mut self, tab_width: usize) -> Sanitizer {
+        assert!(tab_width > 0);
+        self.tab_width = tab_width;
+        self
+    }
+
+    /// Changes the text that replaces characters without a transliteration. It is not
+    /// sanitized itself.
+    pub fn replacement(mut self, replacement: &str) -> Sanitizer {
+        self.replacement = replacement.to_owned();
+        self
+    }
+
+    /// Runs text through the pipeline. The result contains no control characters other
+    /// than `\n`, so it never fails with [Error::NullCharacter](../enum.Error.html).
+    pub fn sanitize(&self, text: &str) -> String {
+        let mut clean = String::with_capacity(text.len());
+        let mut unsupported = String::new();
+
+        for c in text.nfc() {
+            if c == '\t' || c == '\n' {
+                self.transliterate(&mut unsupported, &mut clean);
+                clean.push(c);
+            } else if c.is_control() || is_format(c) {
+                continue;
+            } else if self.charset.contains(c) && (self.charset == Charset::Unicode ||
+                c.width().is_some_and(|w| w > 0))
+            {
+                self.transliterate(&mut unsupported, &mut clean);
+                clean.push(c);
+            } else {
+                unsupported.push(c);
+            }
+        }
+        self.transliterate(&mut unsupported, &mut clean);
+
+        self.expand_tabs(&clean)
+    }
+
+    /// Sanitizes text, then truncates and pads it to exactly `width` columns, see
+    /// [fit](fn.fit.html). Newlines are replaced by spaces.
+    pub fn fit(&self, text: &str, width: usize, align: Align) -> String {
+        fit(&self.sanitize(text).replace('\n', " "), width, align)
+    }
+
+    /// Appends the transliteration of a run of unsupported characters. Runs are
+    /// transliterated as a whole so words are spaced the way deunicode spaces them.
+    fn transliterate(&self, unsupported: &mut String, out: &mut String) {
+        if unsupported.is_empty() {
+            return;
+        }
+        let ascii = deunicode::deunicode_with_tofu(unsupported, "\0");
+        for c in ascii.chars() {
+            match c {
+                '\0' => out.push_str(&self.replacement),
+                c if Charset::Ascii.contains(c) => out.push(c),
+                _ => {},
+            }
+        }
+        unsupported.clear();
+    }
+
+    fn expand_tabs(&self, text: &str) -> String {
+        if !text.contains('\t') {
+            return text.to_owned();
+        }
+
+        let mut out = String::with_capacity(text.len());
+        let mut column = 0;
+        for c in text.chars() {
+            match c {
+                '\t' => {
+                    let spaces = self.tab_width - column % self.tab_width;
+                    out.extend(std::iter::repeat_n(' ', spaces));
+                    column += spaces;
+                },
+                '\n' => {
+                    out.push(c);
+                    column = 0;
+                },
+                c => {
+                    out.push(c);
+                    column += c.width().unwrap_or(0);
+                },
+            }
+        }
+        out
+    }
+}
+
+impl Default for Sanitizer {
+    fn default() -> Sanitizer {
+        Sanitizer::new()
+    }
+}
+
+/// Invisible formatting characters like zero width joiners and byte order marks, general
+/// category Cf.
+fn is_format(c: char) -> bool {
+    matches!(c,
+        '\u{ad}' | '\u{600}'..='\u{605}' | '\u{61c}' | '\u{6dd}' | '\u{70f}' | '\u{180e}' |
+        '\u{200b}'..='\u{200f}' | '\u{202a}'..='\u{202e}' | '\u{2060}'..='\u{2064}' |
+        '\u{2066}'..='\u{206f}' | '\u{feff}' | '\u{fff9}'..='\u{fffb}' |
+        '\u{e0001}' | '\u{e0020}'..='\u{e007f}')
+}
+
+/// Columns taken by text on a text line. Wide characters take two columns, combining
+/// marks none.
+pub fn width(text: &str) -> usize {
+    text.chars().map(|c| c.width().unwrap_or(0)).sum()
+}
+
+/// Shortens text to at most `width` columns, replacing the end with
+/// [ELLIPSIS](../text/constant.ELLIPSIS.html) if anything was cut. Text that fits is
+/// returned unchanged. A wide character that would straddle the limit is cut as a whole.
+pub fn truncate(text: &str, width: usize) -> Cow<'_, str> {
+    if self::width(text) <= width {
+        return Cow::Borrowed(text);
+    }
+
+    let ellipsis_len = ELLIPSIS.len();
+    if width <= ellipsis_len {
+        return Cow::Owned(ELLIPSIS[..width].to_owned());
+    }
+
+    let mut out = String::with_capacity(width);
+    let mut used = 0;
+    for c in text.chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w > width - ellipsis_len {
+            break;
+        }
+        out.push(c);
+        used += w;
+    }
+    out.push_str(ELLIPSIS);
+    Cow::Owned(out)
+}
+
+/// Pads text with spaces to exactly `width` columns, aligned within that width. Text
+/// that is too wide is returned unchanged.
+pub fn pad(text: &str, width: usize, align: Align) -> String {
+    let space = width.saturating_sub(self::width(text));
+    let left = match align {
+        Align::Left => 0,
+        Align::Center => space / 2,
+        Align::Right => space,
+    };
+
+    let mut out = String::with_capacity(text.len() + space);
+    out.extend(std::iter::repeat_n(' ', left));
+    out.push_str(text);
+    out.extend(std::iter::repeat_n(' ', space - left));
+    out
+}
+
+/// Truncates and pads text to exactly `width` columns, see [truncate](fn.truncate.html)
+/// and [pad](fn.pad.html).
+pub fn fit(text: &str, width: usize, align: Align) -> String {
+    pad(&truncate(text, width), width, align)
+}
+
+#[cfg(test)]
+mod tests {
+    use font::Align;
+    use super::{fit, truncate, width, Charset, Sanitizer};
+
+    #[test]
+    fn expands_tabs_to_the_next_stop() {
+        let sanitizer = Sanitizer::new();
+        assert_eq!(sanitizer.sanitize("\tx"), "    x");
+        assert_eq!(sanitizer.sanitize("ab\tx"), "ab  x");
+        assert_eq!(sanitizer.sanitize("abcd\tx"), "abcd    x");
+        assert_eq!(sanitizer.sanitize("a\t\tx"), "a       x");
+        assert_eq!(sanitizer.sanitize("abc\nd\tx"), "abc\nd   x");
+        assert_eq!(Sanitizer::new().tab_width(3).sanitize("a\tb\tc"), "a  b  c");
+        let wide = Sanitizer::new().charset(Charset::Unicode);
+        assert_eq!(wide.sanitize("\u{5317}\tx"), "\u{5317}  x");
+    }
+
+    #[test]
+    fn strips_control_and_format_characters() {
+        let sanitizer = Sanitizer::new();
+        assert_eq!(sanitizer.sanitize("a\u{0}b\u{7}c\u{1b}d\u{7f}e\u{85}f"), "abcdef");
+        assert_eq!(sanitizer.sanitize("\u{feff}zero\u{200b}width\u{200d}"), "zerowidth");
+        assert_eq!(sanitizer.sanitize("one\r\ntwo\rthree"), "one\ntwothree");
+        let wide = Sanitizer::new().charset(Charset::Unicode);
+        assert_eq!(wide.sanitize("\u{202e}rtl\u{2066}"), "rtl");
+    }
+
+    #[test]
+    fn composes_to_nfc() {
+        let sanitizer = Sanitizer::new();
+        assert_eq!(sanitizer.sanitize("e\u{301}"), "\u{e9}");
+        assert_eq!(sanitizer.sanitize("e\u{301}").chars().count(), 1);
+        // No precomposed character, the mark can't be shown on its own and is dropped.
+        assert_eq!(sanitizer.sanitize("q\u{301}"), "q");
+        assert_eq!(Sanitizer::new().charset(Charset::Ascii).sanitize("e\u{301}"), "e");
+    }
+
+    #[test]
+    fn transliterates_outside_the_charset() {
+        let sanitizer = Sanitizer::new();
+        assert_eq!(sanitizer.sanitize("\u{e9}\u{3a9}"), "\u{e9}O");
+        assert_eq!(Sanitizer::new().charset(Charset::Ascii).sanitize("\u{e9}t\u{e9}"), "ete");
+        assert_eq!(Sanitizer::new().charset(Charset::Unicode).sanitize("\u{3a9}"), "\u{3a9}");
+    }
+
+    #[test]
+    fn replaces_characters_without_transliteration() {
+        assert_eq!(Sanitizer::new().sanitize("a\u{e000}b"), "a?b");
+        assert_eq!(Sanitizer::new().replacement("<?>").sanitize("a\u{e000}b"), "a<?>b");
+        assert_eq!(Sanitizer::new().replacement("").sanitize("a\u{e000}b"), "ab");
+        // The replacement is used as is, even if it isn't in the charset.
+        assert_eq!(Sanitizer::new().replacement("\u{fffd}").sanitize("\u{e000}"), "\u{fffd}");
+    }
+
+    #[test]
+    fn counts_columns() {
+        assert_eq!(width("abc"), 3);
+        assert_eq!(width("\u{5317}\u{4eac}"), 4);
+        assert_eq!(width("\u{ff21}"), 2);
+        assert_eq!(width("e\u{301}"), 1);
+        assert_eq!(width(""), 0);
+    }
+
+    #[test]
+    fn fits_wide_characters_by_columns() {
+        assert_eq!(truncate("\u{5317}\u{4eac}\u{5e02}", 6), "\u{5317}\u{4eac}\u{5e02}");
+        assert_eq!(truncate("\u{5317}\u{4eac}\u{5e02}", 5), "\u{5317}...");
+        assert_eq!(fit("\u{5317}", 4, Align::Center), " \u{5317} ");
+        assert_eq!(width(&fit("\u{5317}\u{4eac}\u{5e02}x", 6, Align::Left)), 6);
+    }
+}
@@ -0,0 +1,205 @@
+//! Antialiased vector graphics.
+//!
+//! Requires the `vector` cargo feature.
+//!
+//! A [VectorCanvas](struct.VectorCanvas.html) renders paths with the
+//! [tiny-skia](https://docs.rs/tiny-skia) rasterizer, which is re-exported as
+//! [tiny_skia](tiny_skia/index.html). Paths support lines and quadratic and cubic bezier
+//! curves, paints support solid colors and linear and radial gradients, strokes support
+//! widths, caps, joins and dashes, and everything can be transformed and alpha blended.
+//!
+//! The result is converted to the BGRA layout of
+//! [set_color_background](../struct.Driver.html#method.set_color_background), composited
+//! onto an existing [Canvas](../canvas/trait.Canvas.html), or converted to a
+//! [set_mono_background](../struct.Driver.html#method.set_mono_background) buffer with
+//! thresholding or dithering.
+//!
+//! ```
+//! use logitech_lcd::color::Argb;
+//! use logitech_lcd::dither::Dither;
+//! use logitech_lcd::vector::VectorCanvas;
+//! use logitech_lcd::vector::tiny_skia::{LineCap, Paint, PathBuilder, Stroke, Transform};
+//!
+//! // A gauge needle, rotated around the center of the screen.
+//! let mut path = PathBuilder::new();
+//! path.move_to(160.0, 120.0);
+//! path.line_to(160.0, 30.0);
+//! let path = path.finish().unwrap();
+//!
+//! let mut paint = Paint::default();
+//! paint.set_color(Argb::opaque(255, 80, 0).into());
+//! let stroke = Stroke { width: 6.0, line_cap: LineCap::Round, ..Stroke::default() };
+//!
+//! let mut canvas = VectorCanvas::color();
+//! canvas.clear(Argb::opaque(0, 0, 0));
+//! canvas.stroke_path(&path, &paint, &stroke, Transform::from_rotate_at(45.0, 160.0, 120.0));
+//!
+//! let background = canvas.to_color_background();
+//! assert_eq!(background.len(), logitech_lcd::COLOR_WIDTH * logitech_lcd::COLOR_HEIGHT * 4);
+//!
+//! let mono = VectorCanvas::mono().to_mono_background(Dither::Threshold);
+//! assert_eq!(mono.len(), logitech_lcd::MONO_WIDTH * logitech_lcd::MONO_HEIGHT);
+//! ```
+
+pub extern crate tiny_skia;
+
+use self::tiny_skia::{Color, FillRule, Paint, Path, Pixmap, Stroke, Transform};
+
+use canvas::Canvas;
+use color::Argb;
+use dither::{dither, Dither};
+use resample::luma;
+use {COLOR_WIDTH, COLOR_HEIGHT, MONO_WIDTH, MONO_HEIGHT};
+
+/// An antialiased drawing surface with premultiplied RGBA pixels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorCanvas {
+    pixmap: Pixmap,
+}
+
+impl VectorCanvas {
+    /// Creates a transparent canvas.
+    ///
+    /// Panics:
+    /// - If width or height is zero or the canvas is too large to allocate.
+    ///
+    pub fn new(width: usize, height: usize) -> VectorCanvas {
+        VectorCanvas {
+            pixmap: Pixmap::new(width as u32, height as u32).expect("invalid canvas size"),
+        }
+    }
+
+    /// Creates a transparent canvas the size of the color LCD, 320x240.
+    pub fn color() -> VectorCanvas {
+        VectorCanvas::new(COLOR_WIDTH, COLOR_HEIGHT)
+    }
+
+    /// Creates a transparent canvas the size of the monochrome LCD, 160x43.
+    pub fn mono() -> VectorCanvas {
+        VectorCanvas::new(MONO_WIDTH, MONO_HEIGHT)
+    }
+
+    /// Width in pixels.
+    pub fn width(&self) -> usize {
+        self.pixmap.width() as usize
+    }
+
+    /// Height in pixels.
+    pub fn height(&self) -> usize {
+        self.pixmap.height() as usize
+    }
+
+    /// The underlying pixmap.
+    pub fn pixmap(&self) -> &Pixmap {
+        &self.pixmap
+    }
+
+    /// The underlying pixmap, for the parts of the tiny-skia API not wrapped here, like
+    /// masks, patterns and drawing other pixmaps.
+    pub fn pixmap_mut(&mut self) -> &mut Pixmap {
+        &mut self.pixmap
+    }
+
+    /// Fills the whole canvas with one color, replacing its contents.
+    pub fn clear(&mut self, color: Argb) {
+        self.pixmap.fill(color.into());
+    }
+
+    /// Fills a path with the non-zero winding rule.
+    pub fn fill_path(&mut self, path: &Path, paint: &Paint, transform: Transform) {
+        self.pixmap.fill_path(path, paint, FillRule::Winding, transform, None);
+    }
+
+    /// Strokes the outline of a path.
+    pub fn stroke_path(&mut self, path: &Path, paint: &Paint, stroke: &Stroke,
+        transform: Transform)
+    {
+        self.pixmap.stroke_path(path, paint, stroke, transform, None);
+    }
+
+    /// Reads a pixel with straight alpha.
+    ///
+    /// Panics:
+    /// - If the coordinate is outside the canvas.
+    ///
+    pub fn pixel(&self, x: usize, y: usize) -> Argb {
+        assert!(x < self.width() && y < self.height());
+        let c = self.pixmap.pixels()[y * self.width() + x].demultiply();
+        Argb::new(c.alpha(), c.red(), c.green(), c.blue())
+    }
+
+    /// Writes the canvas as BGRA with straight alpha.
+    ///
+    /// Panics:
+    /// - If dst's length is not `width * height * 4` bytes.
+    ///
+    pub fn write_bgra(&self, dst: &mut [u8]) {
+        assert_eq!(dst.len(), self.width() * self.height() * 4);
+        for (px, out) in self.pixmap.pixels().iter().zip(dst.chunks_mut(4)) {
+            let c = px.demultiply();
+            out.copy_from_slice(&[c.blue(), c.green(), c.red(), c.alpha()]);
+        }
+    }
+
+    /// Converts to a newly allocated color background.
+    ///
+    /// Panics:
+    /// - If the canvas is not 320x240 pixels.
+    ///
+    pub fn to_color_background(&self) -> Vec<u8> {
+        assert!(self.width() == COLOR_WIDTH && self.height() == COLOR_HEIGHT);
+        let mut dst = vec![0; COLOR_WIDTH * COLOR_HEIGHT * 4];
+        self.write_bgra(&mut dst);
+        dst
+    }
+
+    /// Composites the canvas onto a color canvas with its top left corner at (x, y),
+    /// respecting the alpha of every pixel. Pixels outside the target's clip rect are
+    /// clipped.
+    pub fn draw_onto<C: Canvas<Pixel = Argb>>(&self, canvas: &mut C, x: isize, y: isize) {
+        for sy in 0..self.height() {
+            for sx in 0..self.width() {
+                let px = self.pixel(sx, sy);
+                if px.a != 0 {
+                    canvas.put_blended(x + sx as isize, y + sy as isize, px, 255);
+                }
+            }
+        }
+    }
+
+    /// Writes the canvas as 8-bit grayscale, composited onto black.
+    ///
+    /// Panics:
+    /// - If dst's length is not `width * height` bytes.
+    ///
+    pub fn write_luma(&self, dst: &mut [u8]) {
+        assert_eq!(dst.len(), self.width() * self.height());
+        // Premultiplied channels are already composited onto black.
+        for (px, out) in self.pixmap.pixels().iter().zip(dst.iter_mut()) {
+            *out = luma(px.red(), px.green(), px.blue());
+        }
+    }
+
+    /// Converts to a newly allocated monochrome background, composited onto black.
+    ///
+    /// Parameters:
+    /// - method: [Dither::Threshold](../dither/enum.Dither.html) for crisp shapes, one of
+    ///   the dithering methods for gradients.
+    ///
+    /// Panics:
+    /// - If the canvas is not 160x43 pixels.
+    ///
+    pub fn to_mono_background(&self, method: Dither) -> Vec<u8> {
+        assert!(self.width() == MONO_WIDTH && self.height() == MONO_HEIGHT);
+        let mut dst = vec![0; MONO_WIDTH * MONO_HEIGHT];
+        self.write_luma(&mut dst);
+        dither(&mut dst, MONO_WIDTH, MONO_HEIGHT, method);
+        dst
+    }
+}
+
+impl From<Argb> for Color {
+    fn from(c: Argb) -> Color {
+        Color::from_rgba8(c.r, c.g, c.b, c.a)
+    }
+}
@@ -0,0 +1,92 @@
+#!/usr/bin/env python3
+"""Generates src/font/data.rs, the glyph data of the bundled fonts.
+
+The glyphs are taken from the X11 misc-fixed BDF fonts. Run from anywhere:
+
+    python3 tools/fonts.py <bdf-dir>
+
+<bdf-dir> must contain 4x6.bdf, 5x7.bdf, 6x10.bdf and 8x13.bdf, for example the copies
+shipped in the `fonts/src` directory of the embedded-graphics crate.
+"""
+
+import os
+import sys
+
+FONTS = ["4x6", "5x7", "6x10", "8x13"]
+OUTPUT = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "src", "font", "data.rs")
+
+HEADER = """\
+//! Glyph data for the bundled fonts.
+//!
+//! Generated by `tools/fonts.py` from the public domain X11 misc-fixed fonts. Each glyph is
+//! one byte per row, top to bottom, with the leftmost pixel in the most significant bit.
+//! Glyphs cover the printable ASCII range, from `' '` to `'~'`.
+"""
+
+
+def load(path):
+    glyphs = {}
+    ascent = height = None
+    glyph = rows = None
+    with open(path, encoding="latin-1") as f:
+        for line in f:
+            words = line.split()
+            if not words:
+                continue
+            if words[0] == "FONT_ASCENT":
+                ascent = int(words[1])
+            elif words[0] == "FONTBOUNDINGBOX":
+                height = int(words[2])
+            elif words[0] == "ENCODING":
+                glyph = {"encoding": int(words[1])}
+            elif words[0] == "BBX":
+                glyph["bbx"] = [int(w) for w in words[1:]]
+            elif words[0] == "BITMAP":
+                rows = []
+            elif words[0] == "ENDCHAR":
+                glyph["rows"] = rows
+                glyphs[glyph["encoding"]] = glyph
+                rows = None
+            elif rows is not None:
+                # Rows are left aligned and padded to whole bytes; only the first byte is
+                # needed for glyphs up to 8 pixels wide.
+                rows.append(int(words[0][:2], 16))
+    return glyphs, ascent, height
+
+
+def rasterize(glyph, ascent, height):
+    _, h, x_off, y_off = glyph["bbx"]
+    out = [0] * height
+    top = ascent - (h + y_off)
+    for i, row in enumerate(glyph["rows"]):
+        y = top + i
+        if 0 <= y < height:
+            out[y] = (row >> x_off) if x_off >= 0 else (row << -x_off) & 0xff
+    return out
+
+
+def label(c):
+    if c in "'\\":
+        return "'\\" + c + "'"
+    return "'" + c + "'"
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit(__doc__)
+    parts = [HEADER]
+    for name in FONTS:
+        glyphs, ascent, height = load(os.path.join(sys.argv[1], name + ".bdf"))
+        lines = []
+        for code in range(ord(" "), ord("~") + 1):
+            rows = rasterize(glyphs[code], ascent, height)
+            lines.append("    " + " ".join("0x%02x," % r for r in rows) + " // " + label(chr(code)))
+        ident = "GLYPHS_" + name.upper()
+        body = "\n".join(lines)
+        parts.append("pub const %s: [u8; %d] = [\n%s\n];\n" % (ident, 95 * height, body))
+    with open(OUTPUT, "w") as f:
+        f.write("\n".join(parts))
+
+
+if __name__ == "__main__":
+    main()
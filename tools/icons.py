@@ -0,0 +1,269 @@
+#!/usr/bin/env python3
+"""Generates src/icon/data.rs, the pixel data of the bundled icons.
+
+The 8x8 icons are drawn by hand below, the other sizes are rendered from the vector
+outlines in VECTOR with 8x8 supersampling. Run from anywhere:
+
+    python3 tools/icons.py           # rewrite src/icon/data.rs
+    python3 tools/icons.py --show    # print an ASCII preview of every icon instead
+"""
+
+import math
+import os
+import sys
+
+NAMES = ["battery","wifi","cpu","clock","volume","mute","play","pause","warning",
+         "arrow_up","arrow_down","arrow_left","arrow_right"]
+
+# Hand drawn 8x8 icons.
+SMALL = {
+"battery": """
+........
+######..
+#....#..
+#.##.##.
+#.##.##.
+#....#..
+######..
+........""",
+"wifi": """
+........
+.######.
+#......#
+..####..
+.#....#.
+...##...
+...##...
+........""",
+"cpu": """
+.#.#.#..
+#######.
+.#...#..
+##.#.##.
+.#...#..
+#######.
+.#.#.#..
+........""",
+"clock": """
+..###...
+.#...#..
+#..#..#.
+#..##.#.
+#.....#.
+.#...#..
+..###...
+........""",
+"volume": """
+...#....
+..##..#.
+####.#..
+####.#..
+####.#..
+..##..#.
+...#....
+........""",
+"mute": """
+...#....
+..##....
+####.#.#
+####..#.
+####.#.#
+..##....
+...#....
+........""",
+"play": """
+.#......
+.##.....
+.###....
+.####...
+.###....
+.##.....
+.#......
+........""",
+"pause": """
+........
+.##.##..
+.##.##..
+.##.##..
+.##.##..
+.##.##..
+.##.##..
+........""",
+"warning": """
+...#....
+..###...
+..#.#...
+.##.##..
+.#####..
+##.#.##.
+#######.
+........""",
+"arrow_up": """
+...#....
+..###...
+.#####..
+#######.
+..###...
+..###...
+..###...
+........""",
+}
+def rot(rows):  # rotate 90 degrees clockwise
+    n = len(rows)
+    return ["".join(rows[n-1-c][r] for c in range(n)) for r in range(n)]
+def parse(s): return [l for l in s.strip().split("\n")]
+up = parse(SMALL["arrow_up"])
+# Arrow up occupies columns 0..6; rotations keep it within the 7x7 box at the top left.
+box = [r[:7] for r in up[:7]]
+right = rot(box); down = rot(right); left = rot(down)
+pad = lambda b: [r + "." for r in b] + ["........"]
+SMALL["arrow_right"] = "\n".join(pad(right))
+SMALL["arrow_down"] = "\n".join(pad(down))
+SMALL["arrow_left"] = "\n".join(pad(left))
+
+# Vector icons in a unit square, each a list of (op, shape) with op "+" or "-".
+def rect(x0, y0, x1, y1): return lambda x, y: x0 <= x < x1 and y0 <= y < y1
+def circle(cx, cy, r): return lambda x, y: (x-cx)**2 + (y-cy)**2 <= r*r
+def ring(cx, cy, r, t, a0=None, a1=None):
+    def f(x, y):
+        d = math.hypot(x-cx, y-cy)
+        if not (r - t <= d <= r): return False
+        if a0 is None: return True
+        a = math.degrees(math.atan2(cy - y, x - cx))
+        return a0 <= a <= a1
+    return f
+def poly(*pts):
+    def f(x, y):
+        inside = False
+        for i in range(len(pts)):
+            (x0, y0), (x1, y1) = pts[i], pts[(i+1) % len(pts)]
+            if (y0 <= y < y1) or (y1 <= y < y0):
+                if x < x0 + (y - y0) * (x1 - x0) / (y1 - y0):
+                    inside = not inside
+        return inside
+    return f
+def thick_line(x0, y0, x1, y1, w):
+    def f(x, y):
+        dx, dy = x1-x0, y1-y0
+        t = max(0, min(1, ((x-x0)*dx + (y-y0)*dy) / (dx*dx + dy*dy)))
+        return math.hypot(x - x0 - t*dx, y - y0 - t*dy) <= w/2
+    return f
+def speaker(): return [("+", rect(.08, .35, .3, .65)), ("+", poly((.28, .35), (.52, .12), (.52, .88), (.28, .65)))]
+def arrow(): return [("+", poly((.5, .06), (.92, .5), (.08, .5))), ("+", rect(.38, .48, .62, .94))]
+def rotate(shapes, k):
+    def wrap(f):
+        def g(x, y):
+            for _ in range(k):
+                x, y = y, 1 - x   # sample from the shape rotated clockwise
+            return f(x, y)
+        return g
+    return [(op, wrap(f)) for op, f in shapes]
+
+VECTOR = {
+"battery": [("+", rect(.04, .24, .86, .76)), ("-", rect(.12, .32, .78, .68)),
+            ("+", rect(.86, .38, .96, .62)), ("+", rect(.2, .4, .66, .6))],
+"wifi": [("+", ring(.5, .84, .72, .14, 45, 135)), ("+", ring(.5, .84, .46, .14, 45, 135)),
+         ("+", circle(.5, .84, .12))],
+"cpu": [("+", rect(.2, .2, .8, .8)), ("-", rect(.3, .3, .7, .7)), ("+", rect(.4, .4, .6, .6))] +
+       [("+", rect(p, .04, p + .1, .2)) for p in (.25, .45, .65)] +
+       [("+", rect(p, .8, p + .1, .96)) for p in (.25, .45, .65)] +
+       [("+", rect(.04, p, .2, p + .1)) for p in (.25, .45, .65)] +
+       [("+", rect(.8, p, .96, p + .1)) for p in (.25, .45, .65)],
+"clock": [("+", ring(.5, .5, .46, .1)), ("+", thick_line(.5, .5, .5, .2, .09)),
+          ("+", thick_line(.5, .5, .72, .5, .09))],
+"volume": speaker() + [("+", ring(.5, .5, .24, .08, -50, 50)), ("+", ring(.5, .5, .42, .08, -50, 50))],
+"mute": speaker() + [("+", thick_line(.64, .34, .92, .66, .09)), ("+", thick_line(.64, .66, .92, .34, .09))],
+"play": [("+", poly((.22, .08), (.9, .5), (.22, .92)))],
+"pause": [("+", rect(.2, .1, .4, .9)), ("+", rect(.6, .1, .8, .9))],
+"warning": [("+", poly((.5, .04), (.97, .92), (.03, .92))), ("-", rect(.44, .34, .56, .66)),
+            ("-", rect(.44, .72, .56, .84))],
+"arrow_up": arrow(),
+"arrow_right": rotate(arrow(), 1),
+"arrow_down": rotate(arrow(), 2),
+"arrow_left": rotate(arrow(), 3),
+}
+
+def render(shapes, size, ss=8):
+    out = []
+    for py in range(size):
+        row = []
+        for px in range(size):
+            hits = 0
+            for sy in range(ss):
+                for sx in range(ss):
+                    x = (px + (sx + .5) / ss) / size
+                    y = (py + (sy + .5) / ss) / size
+                    v = False
+                    for op, f in shapes:
+                        if f(x, y): v = (op == "+")
+                    hits += v
+            row.append(round(255 * hits / (ss * ss)))
+        out.append(row)
+    return out
+
+def mono_rows(cov):
+    rows = []
+    for r in cov:
+        bits = "".join("#" if v >= 128 else "." for v in r)
+        rows.append(bits)
+    return rows
+
+def pack(rows):
+    data = []
+    for r in rows:
+        for i in range(0, len(r), 8):
+            b = 0
+            for j, ch in enumerate(r[i:i+8]):
+                if ch == "#": b |= 0x80 >> j
+            data.append(b)
+    return data
+
+icons = {}
+for n in NAMES:
+    s8 = parse(SMALL[n]); assert len(s8) == 8 and all(len(r) == 8 for r in s8), n
+    s16 = mono_rows(render(VECTOR[n], 16))
+    c24 = render(VECTOR[n], 24)
+    c32 = render(VECTOR[n], 32)
+    icons[n] = (s8, s16, c24, c32)
+
+if "--show" in sys.argv:
+    for n in NAMES:
+        s8, s16, c24, c32 = icons[n]
+        print(n)
+        shade = " .:-=+*#%@"
+        l32 = ["".join(shade[min(9, v * 10 // 256)] for v in r) for r in c32]
+        l24 = ["".join(shade[min(9, v * 10 // 256)] for v in r) for r in c24]
+        for i in range(32):
+            a = s8[i] if i < 8 else " " * 8
+            b = s16[i] if i < 16 else " " * 16
+            c = l24[i] if i < 24 else " " * 24
+            print(a, " ", b, " ", c, " ", l32[i])
+    sys.exit()
+
+out = ["//! Pixel data for the bundled icons.",
+       "//!",
+       "//! Generated by `tools/icons.py` from vector outlines, the 8x8 icons are drawn by hand.",
+       "//! The monochrome sizes are one bit per pixel, each row `ceil(size / 8)` bytes with the",
+       "//! leftmost pixel in the most significant bit. The color sizes are one coverage byte per",
+       "//! pixel. Icons are stored in the order of the `Icon` variants, rows top to bottom.",
+       "//! Don't edit by hand, change the script and rerun it.",
+       ""]
+def emit(const, size, rows_of, bytes_per_row, per_line=False):
+    total = len(NAMES) * size * bytes_per_row
+    out.append("pub const %s: [u8; %d] = [" % (const, total))
+    for n in NAMES:
+        flat = [b for r in rows_of(n) for b in r]
+        if per_line:
+            out.append("    " + " ".join("0x%02x," % b for b in flat) + " // " + n)
+            continue
+        out.append("    // " + n)
+        for i in range(0, len(flat), 16):
+            out.append("    " + " ".join("0x%02x," % b for b in flat[i:i + 16]))
+    out.append("];")
+    out.append("")
+emit("MONO_8", 8, lambda n: [pack([r]) for r in icons[n][0]], 1, True)
+emit("MONO_16", 16, lambda n: [pack([r]) for r in icons[n][1]], 2)
+emit("COLOR_24", 24, lambda n: icons[n][2], 24)
+emit("COLOR_32", 32, lambda n: icons[n][3], 32)
+path = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "src", "icon", "data.rs")
+open(path, "w").write("\n".join(out).rstrip("\n") + "\n")